@@ -1,6 +1,20 @@
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
+use log::{error, info, warn};
+use rl_model::analysis::{
+    self, concurrency_matrix, interference_table, interferences, Configuration, Diagnostic,
+    Severity, StateSpace, Table,
+};
+use rl_model::doc::*;
+use rl_model::export::*;
+use rl_model::graph::*;
+use rl_model::model::*;
+use rl_model::parser::parse_expr;
+use rl_model::planner::{self, contingent_plan};
+use rl_model::symbolic::SymbolicSpace;
 use rl_model::*;
 use std::env;
+use std::fs;
+use std::path::Path;
 
 /// Simple program to greet a person
 #[derive(Parser, Debug)]
@@ -12,6 +26,365 @@ struct Args {
     /// verbose level
     #[arg(short, long, default_value_t = 1)]
     verbose: u8,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// export the skillset to a verification language
+    Export {
+        /// output format
+        #[arg(long, value_enum)]
+        format: ExportFormat,
+        /// output file (standard output by default)
+        #[arg(short, long)]
+        output: Option<String>,
+        /// goal expression (PDDL problem)
+        #[arg(long)]
+        goal: Option<String>,
+        /// property expression to check (SMT-LIB, Promela, TLA+)
+        #[arg(long)]
+        property: Option<String>,
+        /// number of unrolled steps (SMT-LIB)
+        #[arg(long, default_value_t = 10)]
+        steps: usize,
+    },
+    /// find a sequence of skills from the initial state to a goal
+    Plan {
+        /// goal expression
+        #[arg(long)]
+        goal: String,
+        /// plan for the failures of the skills
+        #[arg(long)]
+        contingent: bool,
+        /// maximal number of skills on each branch of a contingent plan
+        #[arg(long, default_value_t = 10)]
+        depth: usize,
+    },
+    /// check the skillset for deadlocks and other defects
+    Analyze {
+        /// explore the configurations with BDDs
+        #[arg(long)]
+        symbolic: bool,
+    },
+    /// check a property on the reachable configurations
+    Check {
+        /// expression that must hold in every reachable configuration
+        #[arg(long)]
+        property: Option<String>,
+        /// expression that must hold in some reachable configuration
+        #[arg(long)]
+        reach: Option<String>,
+        /// explore the configurations with BDDs
+        #[arg(long)]
+        symbolic: bool,
+    },
+    /// list the events that can violate the invariants of the skills
+    Interference {
+        /// output format
+        #[arg(long, value_enum, default_value_t = TableFormat::Text)]
+        format: TableFormat,
+        /// output file (standard output by default)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// compute which skills can run at the same time
+    Concurrency {
+        /// output format
+        #[arg(long, value_enum, default_value_t = TableFormat::Text)]
+        format: TableFormat,
+        /// output file (standard output by default)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// write the reference documentation of the skillset
+    Doc {
+        /// output format
+        #[arg(long, value_enum, default_value_t = DocFormat::Markdown)]
+        format: DocFormat,
+        /// output directory
+        #[arg(short, long, default_value = "doc")]
+        output: String,
+    },
+    /// draw the resources and the lifecycles of the skills
+    Graph {
+        /// output format
+        #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+        format: GraphFormat,
+        /// output file (standard output by default)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum ExportFormat {
+    /// NuSMV/nuXmv model
+    Smv,
+    /// Fiacre specification for Tina
+    Fiacre,
+    /// UPPAAL timed automata
+    Uppaal,
+    /// PDDL domain
+    Pddl,
+    /// PDDL problem from the initial state to the goal
+    PddlProblem,
+    /// SMT-LIB 2 bounded unrolling (unsat when the property holds)
+    Smtlib,
+    /// Promela model for SPIN
+    Promela,
+    /// TLA+ module (and its TLC configuration next to the output file)
+    Tla,
+    /// TLC configuration of the TLA+ module
+    TlaCfg,
+    /// PRISM MDP, or CTMC with rates
+    Prism,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// PlantUML state diagram
+    Plantuml,
+    /// Mermaid state diagram
+    Mermaid,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum DocFormat {
+    /// Markdown pages
+    Markdown,
+    /// HTML pages
+    Html,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum TableFormat {
+    /// aligned text
+    Text,
+    /// comma separated values
+    Csv,
+    /// array of objects
+    Json,
+}
+
+fn export(
+    model: &Skillset,
+    format: ExportFormat,
+    output: &Option<String>,
+    goal: &Option<String>,
+    property: &Option<String>,
+    steps: usize,
+) {
+    let text = match format {
+        ExportFormat::Smv => to_smv(model),
+        ExportFormat::Fiacre => to_fiacre(model),
+        ExportFormat::Uppaal => to_uppaal(model),
+        ExportFormat::Pddl => to_pddl_domain(model),
+        ExportFormat::PddlProblem => {
+            let goal = match parse_goal(model, goal) {
+                Some(goal) => goal,
+                None => return,
+            };
+            let name = format!("{}_problem", model.name());
+            to_pddl_problem(model, &name, &Valuation::initial(model), &goal)
+        }
+        ExportFormat::Smtlib => match property {
+            Some(_) => match parse_goal(model, property) {
+                Some(property) => to_smtlib(model, steps, Some(&property)),
+                None => return,
+            },
+            None => to_smtlib(model, steps, None),
+        },
+        ExportFormat::Promela => match property {
+            Some(_) => match parse_goal(model, property) {
+                Some(property) => to_promela(model, Some(&property)),
+                None => return,
+            },
+            None => to_promela(model, None),
+        },
+        ExportFormat::Prism => to_prism(model),
+        ExportFormat::Tla | ExportFormat::TlaCfg => {
+            let property = match property {
+                Some(_) => match parse_goal(model, property) {
+                    Some(property) => Some(property),
+                    None => return,
+                },
+                None => None,
+            };
+            let cfg = to_tla_cfg(model, property.is_some());
+            match format {
+                ExportFormat::TlaCfg => cfg,
+                _ => {
+                    if let Some(file) = output {
+                        let file = Path::new(file).with_extension("cfg");
+                        write_output(&cfg, &Some(file.to_string_lossy().to_string()));
+                    }
+                    to_tla(model, property.as_ref())
+                }
+            }
+        }
+    };
+    write_output(&text, output);
+}
+
+fn parse_goal(model: &Skillset, goal: &Option<String>) -> Option<Expr> {
+    match goal {
+        Some(goal) => match parse_expr(model, goal) {
+            Ok(expr) => Some(expr),
+            Err(e) => {
+                error!("{}", e);
+                None
+            }
+        },
+        None => {
+            error!("missing goal");
+            None
+        }
+    }
+}
+
+fn plan(model: &Skillset, goal: &str, contingent: bool, depth: usize) {
+    let goal = match parse_goal(model, &Some(goal.to_string())) {
+        Some(goal) => goal,
+        None => return,
+    };
+    let initial = Valuation::initial(model);
+    if contingent {
+        match contingent_plan(model, &initial, &goal, depth) {
+            Some(plan) => print!("{}", plan.to_lang(model)),
+            None => println!("no plan"),
+        }
+    } else {
+        match planner::plan(model, &initial, &goal) {
+            Some(skills) => {
+                for id in skills {
+                    println!("{}", model.get(id).unwrap());
+                }
+            }
+            None => println!("no plan"),
+        }
+    }
+}
+
+fn analyze(model: &Skillset, symbolic: bool) {
+    let diagnostics = if symbolic {
+        analysis::analyze_symbolic(model)
+    } else {
+        analysis::analyze(model)
+    };
+    for d in diagnostics.iter() {
+        match d.severity() {
+            Severity::Warning => warn!("{}", d.to_lang(model)),
+            Severity::Error => error!("{}", d.to_lang(model)),
+        }
+    }
+    if diagnostics.is_empty() {
+        info!("Analysis OK");
+    }
+}
+
+/// A reachable configuration satisfying the expression, with the actions
+/// leading to it.
+fn find(model: &Skillset, expr: &Expr, symbolic: bool) -> Option<(Configuration, Vec<Action>)> {
+    if symbolic {
+        SymbolicSpace::explore(model).is_reachable(expr)
+    } else {
+        let space = StateSpace::explore(model);
+        space
+            .find(|x| expr.eval(x.valuation()))
+            .map(|i| (space.configuration(i).clone(), space.witness(i)))
+    }
+}
+
+fn check(model: &Skillset, property: &Option<String>, reach: &Option<String>, symbolic: bool) {
+    if let Some(property) = property {
+        let property = match parse_goal(model, &Some(property.clone())) {
+            Some(property) => property,
+            None => return,
+        };
+        let violated = Expr::Not(Box::new(property));
+        match find(model, &violated, symbolic) {
+            Some((configuration, witness)) => {
+                let d = Diagnostic::error(
+                    format!("property violated in {}", configuration.to_lang(model)),
+                    None,
+                )
+                .with_witness(witness);
+                println!("{}", d.to_lang(model));
+            }
+            None => println!("property holds"),
+        }
+    }
+    if let Some(reach) = reach {
+        let reach = match parse_goal(model, &Some(reach.clone())) {
+            Some(reach) => reach,
+            None => return,
+        };
+        match find(model, &reach, symbolic) {
+            Some((configuration, witness)) => {
+                let d = Diagnostic::warning(
+                    format!("reachable in {}", configuration.to_lang(model)),
+                    None,
+                )
+                .with_witness(witness);
+                println!("{}", d.to_lang(model));
+            }
+            None => println!("not reachable"),
+        }
+    }
+}
+
+fn interference(model: &Skillset, format: TableFormat, output: &Option<String>) {
+    let table = interference_table(model, &interferences(model));
+    write_table(&table, format, output);
+}
+
+fn graph(model: &Skillset, format: GraphFormat, output: &Option<String>) {
+    let text = match format {
+        GraphFormat::Dot => to_dot(model),
+        GraphFormat::Plantuml => to_plantuml(model),
+        GraphFormat::Mermaid => to_mermaid(model),
+    };
+    write_output(&text, output);
+}
+
+fn doc(model: &Skillset, format: DocFormat, output: &str) {
+    if let Err(e) = fs::create_dir_all(output) {
+        error!("cannot create directory {} {:?}", output, e);
+        return;
+    }
+    for page in documentation(model) {
+        let (text, extension) = match format {
+            DocFormat::Markdown => (to_markdown(&page), "md"),
+            DocFormat::Html => (to_html(&page), "html"),
+        };
+        let file = Path::new(output).join(format!("{}.{}", page.name(), extension));
+        write_output(&text, &Some(file.to_string_lossy().to_string()));
+    }
+    info!("Documentation written in {}", output);
+}
+
+fn write_table(table: &Table, format: TableFormat, output: &Option<String>) {
+    let text = match format {
+        TableFormat::Text => table.to_text(),
+        TableFormat::Csv => table.to_csv(),
+        TableFormat::Json => table.to_json(),
+    };
+    write_output(&text, output);
+}
+
+fn write_output(text: &str, output: &Option<String>) {
+    match output {
+        Some(file) => {
+            if let Err(e) = fs::write(file, text) {
+                error!("cannot write file {} {:?}", file, e);
+            }
+        }
+        None => print!("{}", text),
+    }
 }
 
 fn main() {
@@ -33,5 +406,32 @@ fn main() {
         // if check(&model) {
         //     // TODO
         // }
+        match &args.command {
+            Some(Command::Export {
+                format,
+                output,
+                goal,
+                property,
+                steps,
+            }) => export(&model, *format, output, goal, property, *steps),
+            Some(Command::Plan {
+                goal,
+                contingent,
+                depth,
+            }) => plan(&model, goal, *contingent, *depth),
+            Some(Command::Analyze { symbolic }) => analyze(&model, *symbolic),
+            Some(Command::Check {
+                property,
+                reach,
+                symbolic,
+            }) => check(&model, property, reach, *symbolic),
+            Some(Command::Interference { format, output }) => interference(&model, *format, output),
+            Some(Command::Concurrency { format, output }) => {
+                write_table(&concurrency_matrix(&model), *format, output)
+            }
+            Some(Command::Doc { format, output }) => doc(&model, *format, output),
+            Some(Command::Graph { format, output }) => graph(&model, *format, output),
+            None => {}
+        }
     }
 }
@@ -0,0 +1,21 @@
+skillset Robot {
+    resource R {
+        state { s1 s2 }
+        initial s2
+        transition {
+            @requirement("REQ-1") s1 -> s2
+            s2 -> s1
+        }
+    }
+
+    skill S {
+        interrupt @safe {
+            interrupting false
+            effect R -> s1
+            postcondition @checked named: R == s2
+        }
+        success OK {
+            postcondition R == s2
+        }
+    }
+}
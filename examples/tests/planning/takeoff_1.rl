@@ -0,0 +1,23 @@
+skillset uav {
+    resource {
+        flight_status {
+            state { OnGround InAir }
+            initial OnGround
+            transition all
+        }
+    }
+
+    skill {
+        takeoff {
+            precondition {
+                on_ground: flight_status == OnGround
+            }
+            success ok {
+                postcondition flight_status == InAir
+            }
+            failure ko {
+                postcondition flight_status == OnGround
+            }
+        }
+    }
+}
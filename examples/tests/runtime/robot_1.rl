@@ -0,0 +1,87 @@
+skillset robot<Float> {
+    data {
+        position: Float period 100 ms
+    }
+
+    resource {
+        power {
+            state { On Off }
+            initial On
+            transition all
+        }
+        arm {
+            state { Folded Moving }
+            initial Folded
+            transition all
+        }
+        target {
+            state { Far Reached }
+            initial Far
+            transition all
+        }
+    }
+
+    event {
+        power_off {
+            guard power == On
+            effect power -> Off
+        }
+        power_on {
+            effect power -> On
+        }
+        reach {
+            effect target -> Reached
+        }
+        leave {
+            effect target -> Far
+        }
+    }
+
+    skill move {
+        precondition {
+            powered: power == On
+            folded : arm == Folded
+        }
+        start arm -> Moving
+        invariant {
+            powered {
+                guard  power == On
+                effect arm -> Folded
+            }
+        }
+        progress {
+            period 100 ms
+            message position: Float
+        }
+        interrupt {
+            effect arm -> Folded
+        }
+        success arrived {
+            postcondition target == Reached
+            effect arm -> Folded
+        }
+        failure blocked {
+            effect arm -> Folded
+        }
+    }
+
+    skill watch {
+        precondition {
+            moving: arm == Moving
+        }
+        invariant {
+            arm_moving {
+                guard arm == Moving
+            }
+        }
+        success done {}
+        failure lost {}
+    }
+
+    skill grasp {
+        interrupt {
+            interrupting true
+        }
+        success held {}
+    }
+}
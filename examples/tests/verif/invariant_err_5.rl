@@ -0,0 +1,37 @@
+skillset S {
+    resource {
+        R {
+            state {A B C}
+            initial A
+            transition all
+        }
+        Q {
+            state {On Off}
+            initial Off
+            transition all
+        }
+    }
+
+    constraint safe: not (R == C and Q == On)
+
+    event {
+        on {
+            effect Q -> On
+        }
+        off {
+            effect Q -> Off
+        }
+    }
+
+    skill S {
+        precondition {
+            p: R == A
+            q: Q == Off
+        }
+        start R -> B
+        invariant p1 {
+            guard Q == Off
+            effect R -> C
+        }
+    }
+}
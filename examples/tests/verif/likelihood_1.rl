@@ -0,0 +1,43 @@
+skillset S {
+    resource {
+        R {
+            state {A B}
+            initial A
+            transition all
+        }
+        Q {
+            state {Near Far}
+            initial Far
+            transition all
+        }
+    }
+
+    event {
+        approach {
+            probability 0.5
+            effect Q -> Near
+        }
+        away {
+            effect Q -> Far
+        }
+    }
+
+    skill S {
+        precondition p: R == A
+        start R -> B
+        interrupt {
+            description "S goes back to A"
+            interrupting false
+            effect R -> A
+        }
+        success s {
+            probability 0.7
+            postcondition Q == Near
+            effect R -> A
+        }
+        failure f {
+            probability 0.2
+            effect R -> A
+        }
+    }
+}
@@ -0,0 +1,20 @@
+
+skillset S {
+    resource R {
+        state {A B C}
+        initial A
+        transition {
+            A -> B
+            A -> C
+        }
+    }
+
+    skill S {
+        precondition p: R == A
+        start R -> B
+        success s {
+            effect R -> C
+            postcondition R == B
+        }
+    }
+}
@@ -1,12 +1,4 @@
-type {
-    FrameId
-    Float
-    Vector3
-    Battery
-    GeoPoint
-}
-
-skillset uav {
+skillset uav<FrameId, Float, Vector3, Battery, GeoPoint> {
     data {
         battery: Battery
         position: GeoPoint
@@ -15,8 +7,7 @@ skillset uav {
     }
 
     resource {
-        authority {
-            // extern
+        @extern authority {
             state { Pilot Skill }
             initial Pilot
             transition {
@@ -25,8 +16,7 @@ skillset uav {
             }
         }
 
-        home_status {
-            // extern
+        @extern home_status {
             state { Invalid Valid }
             initial Invalid
             transition {
@@ -35,29 +25,25 @@ skillset uav {
             }
         }
 
-        flight_status {
-            // extern
+        @extern flight_status {
             state { NotReady OnGround InAir }
             initial NotReady
             transition all
         }
 
-        motion {
-            // internal
+        @internal motion {
             state { Free Used }
             initial Free
             transition all
         }
 
-        heading {
-            // internal
-            state { Free Used }
-            initial Free
+        @internal heading {
+            state { HeadingFree HeadingUsed }
+            initial HeadingFree
             transition all
         }
 
-        battery {
-            // extern
+        @extern battery {
             state { Good Low Critical }
             initial Good
             transition {
@@ -117,8 +103,8 @@ skillset uav {
             postcondition authority == Pilot
         }
     }
-    // property: not F(authority == Pilot and (motion == Used or heading == Used))
-    // invariant: not (authority == Pilot and (motion == Used or heading == Used))
+    // property: not F(authority == Pilot and (motion == Used or heading == HeadingUsed))
+    // invariant: not (authority == Pilot and (motion == Used or heading == HeadingUsed))
 
     skill capture_home {
         input {
@@ -167,8 +153,8 @@ skillset uav {
             }
         }
         progress {
-            period 1.0
-            output height: Float
+            period 1 sec
+            message height: Float
         }
         interrupt {
             interrupting true
@@ -237,8 +223,8 @@ skillset uav {
             }
         }
         progress {
-            period 1.0
-            output distance: Float
+            period 1 sec
+            message distance: Float
         }
         interrupt {
             interrupting true
@@ -272,7 +258,7 @@ skillset uav {
             }
         }
         progress {
-            period 1.0
+            period 1 sec
         }
         interrupt {
             interrupting true
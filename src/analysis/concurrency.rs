@@ -0,0 +1,147 @@
+use super::*;
+use crate::model::*;
+
+// Two skills can run together when one of them can start while the other is
+// running: the invariants of the running skill and the preconditions of the
+// other hold, and the invariants of both hold after the start effects.
+
+fn invariants(skill: &Skill) -> Expr {
+    Expr::and_all(skill.invariants().iter().map(|x| x.guard().clone()))
+}
+
+fn preconditions(skill: &Skill) -> Expr {
+    Expr::and_all(skill.preconditions().iter().map(|x| x.expr().clone()))
+}
+
+fn can_start_while_running(skillset: &Skillset, running: &Skill, starting: &Skill) -> bool {
+    let after = Expr::And(
+        Box::new(invariants(running)),
+        Box::new(invariants(starting)),
+    )
+    .before(starting.start());
+    Expr::and_all(vec![invariants(running), preconditions(starting), after])
+        .is_satisfiable(skillset)
+}
+
+pub fn can_run_together(skillset: &Skillset, a: &Skill, b: &Skill) -> bool {
+    a.id() != b.id()
+        && (can_start_while_running(skillset, a, b) || can_start_while_running(skillset, b, a))
+}
+
+/// The resources a skill writes from its start to its termination.
+pub fn written_resources(skill: &Skill) -> Vec<ResourceId> {
+    let mut effects: Vec<&Effect> = skill.start().iter().collect();
+    for x in skill.invariants() {
+        effects.extend(x.effects().iter());
+    }
+    if let Some(interrupt) = skill.interrupt() {
+        effects.extend(interrupt.effects().iter());
+    }
+    for x in skill.successes() {
+        effects.extend(x.effects().iter());
+    }
+    for x in skill.failures() {
+        effects.extend(x.effects().iter());
+    }
+    let mut v = Vec::new();
+    for effect in effects {
+        let id = effect.resource().resolved();
+        if !v.contains(&id) {
+            v.push(id);
+        }
+    }
+    v
+}
+
+/// The resources a skill reads in its preconditions, invariants and
+/// postconditions.
+pub fn read_resources(skill: &Skill) -> Vec<ResourceId> {
+    let mut exprs: Vec<&Expr> = skill.preconditions().iter().map(|x| x.expr()).collect();
+    exprs.extend(skill.invariants().iter().map(|x| x.guard()));
+    if let Some(interrupt) = skill.interrupt() {
+        exprs.extend(interrupt.postconditions().iter().map(|x| x.expr()));
+    }
+    for x in skill.successes() {
+        exprs.extend(x.postconditions().iter().map(|x| x.expr()));
+    }
+    for x in skill.failures() {
+        exprs.extend(x.postconditions().iter().map(|x| x.expr()));
+    }
+    let mut v = Vec::new();
+    for expr in exprs {
+        for id in expr.resources() {
+            if !v.contains(&id) {
+                v.push(id);
+            }
+        }
+    }
+    v
+}
+
+fn shared_resources(a: &Skill, b: &Skill) -> Vec<ResourceId> {
+    let other = written_resources(b);
+    written_resources(a)
+        .into_iter()
+        .filter(|x| other.contains(x))
+        .collect()
+}
+
+fn resource_names(skillset: &Skillset, resources: &[ResourceId]) -> String {
+    let names: Vec<String> = resources
+        .iter()
+        .map(|x| skillset.get(*x).unwrap().name().to_string())
+        .collect();
+    names.join(", ")
+}
+
+/// Pairs of skills that can run together while writing the same resource.
+pub fn concurrency_conflicts(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    let skills = skillset.skills();
+    for (i, a) in skills.iter().enumerate() {
+        for b in skills[i + 1..].iter() {
+            let shared = shared_resources(a, b);
+            if !shared.is_empty() && can_run_together(skillset, a, b) {
+                v.push(Diagnostic::warning(
+                    format!(
+                        "skills {} and {} can run together and both write {}",
+                        a,
+                        b,
+                        resource_names(skillset, &shared)
+                    ),
+                    b.position(),
+                ));
+            }
+        }
+    }
+    v
+}
+
+/// For each pair of skills: "no" if they cannot run together, "yes" if they
+/// can, and "conflict" followed by the resources if they can and both write
+/// these resources.
+pub fn concurrency_matrix(skillset: &Skillset) -> Table {
+    let mut header = vec!["skill".to_string()];
+    header.extend(skillset.skills().iter().map(|x| x.name().to_string()));
+    let mut table = Table::new(header);
+    for a in skillset.skills() {
+        let mut row = vec![a.name().to_string()];
+        for b in skillset.skills() {
+            let cell = if a.id() == b.id() {
+                "-".to_string()
+            } else if !can_run_together(skillset, a, b) {
+                "no".to_string()
+            } else {
+                let shared = shared_resources(a, b);
+                if shared.is_empty() {
+                    "yes".to_string()
+                } else {
+                    format!("conflict ({})", resource_names(skillset, &shared))
+                }
+            };
+            row.push(cell);
+        }
+        table.add_row(row);
+    }
+    table
+}
@@ -0,0 +1,85 @@
+use crate::model::*;
+
+/// A state of the skillset semantics: the resource valuation and the status
+/// of every skill.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Configuration {
+    valuation: Valuation,
+    running: Vec<bool>,
+}
+
+impl Configuration {
+    pub fn new(valuation: Valuation, running: Vec<bool>) -> Self {
+        Self { valuation, running }
+    }
+
+    pub fn initial(skillset: &Skillset) -> Self {
+        Self {
+            valuation: Valuation::initial(skillset),
+            running: vec![false; skillset.skills().len()],
+        }
+    }
+
+    pub fn valuation(&self) -> &Valuation {
+        &self.valuation
+    }
+
+    pub fn is_running(&self, id: SkillId) -> bool {
+        self.running[id.index()]
+    }
+
+    pub fn running(&self) -> Vec<SkillId> {
+        self.running
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x)
+            .map(|(i, _)| SkillId(i))
+            .collect()
+    }
+
+    pub fn is_enabled(&self, skillset: &Skillset, action: &Action) -> bool {
+        let status = match action {
+            Action::Event(_) => true,
+            Action::Start(id) => !self.is_running(*id),
+            _ => self.is_running(action.skill().unwrap()),
+        };
+        status && action.guard(skillset).eval(&self.valuation)
+    }
+
+    pub fn next(&self, skillset: &Skillset, action: &Action) -> Self {
+        let mut next = self.clone();
+        next.valuation.apply(action.effects(skillset));
+        if let (Some(id), Some(running)) = (action.skill(), action.running_after()) {
+            next.running[id.index()] = running;
+        }
+        next
+    }
+
+    pub fn successors(&self, skillset: &Skillset) -> Vec<(Action, Self)> {
+        skillset
+            .actions()
+            .into_iter()
+            .filter(|x| self.is_enabled(skillset, x))
+            .map(|x| (x, self.next(skillset, &x)))
+            .collect()
+    }
+}
+
+impl ToLang for Configuration {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        let running: Vec<String> = self
+            .running()
+            .iter()
+            .map(|x| skillset.get(*x).unwrap().name().to_string())
+            .collect();
+        if running.is_empty() {
+            self.valuation.to_lang(skillset)
+        } else {
+            format!(
+                "{} (running: {})",
+                self.valuation.to_lang(skillset),
+                running.join(", ")
+            )
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use super::*;
+use crate::model::*;
+
+/// Constraints that cannot hold in any state of the resources.
+pub fn unsatisfiable_constraints(skillset: &Skillset) -> Vec<Diagnostic> {
+    skillset
+        .constraints()
+        .iter()
+        .filter(|x| !x.expr().is_satisfiable(skillset))
+        .map(|x| Diagnostic::error(format!("constraint {} can never hold", x), x.position()))
+        .collect()
+}
+
+/// Constraints that are false in a reachable configuration.
+pub fn violated_constraints(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for constraint in skillset.constraints() {
+        let found = space.find(|x| !constraint.expr().eval(x.valuation()));
+        if let Some(i) = found {
+            v.push(
+                Diagnostic::error(
+                    format!(
+                        "constraint {} is violated in {}",
+                        constraint,
+                        space.configuration(i).valuation().to_lang(skillset)
+                    ),
+                    constraint.position(),
+                )
+                .with_witness(space.witness(i)),
+            );
+        }
+    }
+    v
+}
@@ -0,0 +1,85 @@
+use super::*;
+use crate::model::*;
+use crate::parser::Position;
+
+/// Where a deadlock is reported: at the first skill left running, or at the
+/// skillset when none is.
+pub fn deadlock_position(skillset: &Skillset, configuration: &Configuration) -> Option<Position> {
+    match configuration.running().first() {
+        Some(id) => skillset.get(*id).unwrap().position(),
+        None => skillset.position(),
+    }
+}
+
+/// Reachable configurations where no event, skill start or skill termination
+/// is enabled.
+pub fn deadlocks(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for (i, configuration) in space.configurations().iter().enumerate() {
+        if space.successors(i).is_empty() {
+            v.push(
+                Diagnostic::error(
+                    format!("deadlock in {}", configuration.to_lang(skillset)),
+                    deadlock_position(skillset, configuration),
+                )
+                .with_witness(space.witness(i)),
+            );
+        }
+    }
+    v
+}
+
+/// Skills that cannot start in any reachable configuration.
+pub fn never_started(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        let started = (0..space.len()).any(|i| {
+            space
+                .successors(i)
+                .iter()
+                .any(|(action, _)| *action == Action::Start(skill.id()))
+        });
+        if !started {
+            v.push(Diagnostic::warning(
+                format!("skill {} can never start", skill),
+                skill.position(),
+            ));
+        }
+    }
+    v
+}
+
+/// Resource states that are reached and then never left.
+pub fn stuck_resources(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    let predecessors = space.predecessors();
+    for resource in skillset.resources() {
+        if resource.states().len() < 2 {
+            continue;
+        }
+        for state in resource.states() {
+            let other: Vec<usize> = (0..space.len())
+                .filter(|i| space.configuration(*i).valuation().get(resource.id()) != state.id())
+                .collect();
+            let leaving = space.backward_reachable(&predecessors, &other);
+            // configurations are ordered by distance to the initial one
+            if let Some(i) = (0..space.len()).find(|i| !leaving[*i]) {
+                v.push(
+                    Diagnostic::warning(
+                        format!("resource {} is stuck in state {}", resource, state),
+                        state.position(),
+                    )
+                    .with_witness(space.witness(i)),
+                );
+            }
+        }
+    }
+    v
+}
+
+pub fn deadlock_analysis(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = deadlocks(skillset, space);
+    v.extend(never_started(skillset, space));
+    v.extend(stuck_resources(skillset, space));
+    v
+}
@@ -0,0 +1,89 @@
+use crate::model::*;
+use crate::parser::Position;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A finding of an analysis, with the sequence of actions that leads to it
+/// from the initial state when there is one.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    severity: Severity,
+    message: String,
+    position: Option<Position>,
+    witness: Option<Vec<Action>>,
+}
+
+impl Diagnostic {
+    pub fn new<S: Into<String>>(
+        severity: Severity,
+        message: S,
+        position: Option<Position>,
+    ) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            position,
+            witness: None,
+        }
+    }
+
+    pub fn warning<S: Into<String>>(message: S, position: Option<Position>) -> Self {
+        Self::new(Severity::Warning, message, position)
+    }
+
+    pub fn error<S: Into<String>>(message: S, position: Option<Position>) -> Self {
+        Self::new(Severity::Error, message, position)
+    }
+
+    pub fn with_witness(mut self, witness: Vec<Action>) -> Self {
+        self.witness = Some(witness);
+        self
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position.clone()
+    }
+
+    pub fn witness(&self) -> &Option<Vec<Action>> {
+        &self.witness
+    }
+}
+
+impl ToLang for Diagnostic {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        let mut s = match &self.position {
+            Some(position) => format!("{} at {}", self.message, position),
+            None => self.message.clone(),
+        };
+        if let Some(witness) = &self.witness {
+            if witness.is_empty() {
+                s.push_str("\n\twitness: initial state");
+            } else {
+                let v: Vec<String> = witness.iter().map(|x| x.to_lang(skillset)).collect();
+                s.push_str(&format!("\n\twitness: {}", v.join("; ")));
+            }
+        }
+        s
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+use crate::model::*;
+
+/// An event that can violate an invariant of a skill while it is running.
+#[derive(Clone, Debug)]
+pub struct Interference {
+    skill: SkillId,
+    event: EventId,
+    invariant: InvariantId,
+    affected: Vec<SkillId>,
+}
+
+impl Interference {
+    pub fn skill(&self) -> SkillId {
+        self.skill
+    }
+
+    pub fn event(&self) -> EventId {
+        self.event
+    }
+
+    pub fn invariant(&self) -> InvariantId {
+        self.invariant
+    }
+
+    /// The other skills that can run together with the skill, and whose
+    /// invariants read a resource written by the event or by the effects of
+    /// the violated invariant.
+    pub fn affected(&self) -> &Vec<SkillId> {
+        &self.affected
+    }
+}
+
+fn written(effects: &[Effect]) -> Vec<ResourceId> {
+    effects.iter().map(|x| x.resource().resolved()).collect()
+}
+
+fn invariant_resources(skill: &Skill) -> Vec<ResourceId> {
+    skill
+        .invariants()
+        .iter()
+        .flat_map(|x| x.guard().resources())
+        .collect()
+}
+
+/// For each skill and event, the invariants of the skill that the event can
+/// violate from a state where all of them hold.
+pub fn interferences(skillset: &Skillset) -> Vec<Interference> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        let invariants = Expr::and_all(skill.invariants().iter().map(|x| x.guard().clone()));
+        for event in skillset.events() {
+            let guard = event.guard().clone().unwrap_or(Expr::True);
+            for invariant in skill.invariants() {
+                let violated = Expr::Not(Box::new(invariant.guard().before(event.effects())));
+                let condition = Expr::and_all(vec![invariants.clone(), guard.clone(), violated]);
+                if !condition.is_satisfiable(skillset) {
+                    continue;
+                }
+                let mut resources = written(event.effects());
+                resources.extend(written(invariant.effects()));
+                let affected = skillset
+                    .skills()
+                    .iter()
+                    .filter(|x| can_run_together(skillset, skill, x))
+                    .filter(|x| invariant_resources(x).iter().any(|r| resources.contains(r)))
+                    .map(|x| x.id())
+                    .collect();
+                v.push(Interference {
+                    skill: skill.id(),
+                    event: event.id(),
+                    invariant: invariant.id(),
+                    affected,
+                });
+            }
+        }
+    }
+    v
+}
+
+pub fn interference_table(skillset: &Skillset, interferences: &[Interference]) -> Table {
+    let mut table = Table::new(vec![
+        "skill",
+        "event",
+        "invariant",
+        "invariant effects",
+        "affected skills",
+    ]);
+    for x in interferences.iter() {
+        let invariant = skillset.get(x.invariant()).unwrap();
+        let effects: Vec<String> = invariant
+            .effects()
+            .iter()
+            .map(|e| e.to_lang(skillset))
+            .collect();
+        let affected: Vec<String> = x
+            .affected()
+            .iter()
+            .map(|id| skillset.get(*id).unwrap().name().to_string())
+            .collect();
+        table.add_row(vec![
+            skillset.get(x.skill()).unwrap().name().to_string(),
+            skillset.get(x.event()).unwrap().name().to_string(),
+            invariant.name().to_string(),
+            effects.join(", "),
+            affected.join(", "),
+        ]);
+    }
+    table
+}
@@ -0,0 +1,155 @@
+use super::*;
+use crate::model::*;
+use std::collections::HashSet;
+
+/// Invariants that are false right after the start of their skill in a
+/// reachable configuration.
+pub fn invariants_at_start(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    let mut reported = HashSet::new();
+    for i in 0..space.len() {
+        for (action, j) in space.successors(i).iter() {
+            let skill = match action {
+                Action::Start(id) => skillset.get(*id).unwrap(),
+                _ => continue,
+            };
+            let valuation = space.configuration(*j).valuation();
+            for invariant in skill.invariants() {
+                if invariant.guard().eval(valuation) || !reported.insert(invariant.id()) {
+                    continue;
+                }
+                v.push(
+                    Diagnostic::error(
+                        format!(
+                            "invariant {} of skill {} does not hold after start in {}",
+                            invariant,
+                            skill,
+                            valuation.to_lang(skillset)
+                        ),
+                        invariant.position(),
+                    )
+                    .with_witness(space.witness(*j)),
+                );
+            }
+        }
+    }
+    v
+}
+
+/// Invariant effects that are not transitions of the resources from some
+/// state where the invariant is violated.
+pub fn invariant_effects(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        for invariant in skill.invariants() {
+            let violated = Expr::Not(Box::new(invariant.guard().clone()));
+            for (i, src) in undeclared_effects(skillset, &violated, invariant.effects()) {
+                let effect = &invariant.effects()[i];
+                v.push(Diagnostic::error(
+                    format!(
+                        "effect {} of invariant {} of skill {} is not a transition from {} when the invariant is violated",
+                        effect.to_lang(skillset),
+                        invariant,
+                        skill,
+                        skillset.get(src).unwrap()
+                    ),
+                    effect.position(),
+                ));
+            }
+        }
+    }
+    v
+}
+
+/// Whether the effects can make the condition false from a state where it
+/// holds and the invariant is violated.
+fn breaks(skillset: &Skillset, invariant: &Invariant, condition: &Expr) -> bool {
+    Expr::and_all(vec![
+        Expr::Not(Box::new(invariant.guard().clone())),
+        condition.clone(),
+        Expr::Not(Box::new(condition.before(invariant.effects()))),
+    ])
+    .is_satisfiable(skillset)
+}
+
+/// Invariant effects that can violate a constraint of the skillset or an
+/// invariant of a skill that can run together, from a state where it held.
+pub fn invariant_outcomes(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        for invariant in skill.invariants() {
+            if invariant.effects().is_empty() {
+                continue;
+            }
+            for constraint in skillset.constraints() {
+                if breaks(skillset, invariant, constraint.expr()) {
+                    v.push(Diagnostic::error(
+                        format!(
+                            "effects of invariant {} of skill {} can violate constraint {}",
+                            invariant, skill, constraint
+                        ),
+                        invariant.position(),
+                    ));
+                }
+            }
+            let others = skillset
+                .skills()
+                .iter()
+                .filter(|x| can_run_together(skillset, skill, x));
+            for other in others {
+                for x in other.invariants() {
+                    if breaks(skillset, invariant, x.guard()) {
+                        v.push(Diagnostic::warning(
+                            format!(
+                                "effects of invariant {} of skill {} can violate invariant {} of skill {}",
+                                invariant, skill, x, other
+                            ),
+                            invariant.position(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    v
+}
+
+/// Invariants implied by another invariant of their skill, that is violated
+/// whenever they are. Of equivalent invariants, the last ones are reported.
+pub fn redundant_invariants(skillset: &Skillset) -> Vec<Diagnostic> {
+    let implies = |x: &Invariant, y: &Invariant| {
+        x.guard().is_satisfiable(skillset)
+            && !Expr::And(
+                Box::new(x.guard().clone()),
+                Box::new(Expr::Not(Box::new(y.guard().clone()))),
+            )
+            .is_satisfiable(skillset)
+    };
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        let invariants = skill.invariants();
+        for (j, invariant) in invariants.iter().enumerate() {
+            let implied = invariants.iter().enumerate().find(|(i, x)| {
+                *i != j && implies(x, invariant) && (*i < j || !implies(invariant, x))
+            });
+            if let Some((_, x)) = implied {
+                v.push(Diagnostic::warning(
+                    format!(
+                        "invariant {} of skill {} is implied by invariant {}",
+                        invariant, skill, x
+                    ),
+                    invariant.position(),
+                ));
+            }
+        }
+    }
+    v
+}
+
+pub fn invariant_analysis(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = invariants_at_start(skillset, space);
+    v.extend(invariant_effects(skillset));
+    v.extend(invariant_outcomes(skillset));
+    v.extend(redundant_invariants(skillset));
+    v
+}
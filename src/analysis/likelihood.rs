@@ -0,0 +1,94 @@
+use super::*;
+use crate::model::*;
+use crate::parser::Position;
+
+fn annotations(skillset: &Skillset) -> Vec<(String, Likelihood, Option<Position>)> {
+    let mut v = Vec::new();
+    for event in skillset.events() {
+        if let Some(x) = event.likelihood() {
+            v.push((format!("event {}", event), x, event.position()));
+        }
+    }
+    for skill in skillset.skills() {
+        for x in skill.successes() {
+            if let Some(l) = x.likelihood() {
+                v.push((format!("success {} of skill {}", x, skill), l, x.position()));
+            }
+        }
+        for x in skill.failures() {
+            if let Some(l) = x.likelihood() {
+                v.push((format!("failure {} of skill {}", x, skill), l, x.position()));
+            }
+        }
+    }
+    v
+}
+
+/// Probabilities greater than 1 and null rates.
+pub fn invalid_likelihoods(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for (name, likelihood, position) in annotations(skillset) {
+        let valid = match likelihood {
+            Likelihood::Probability(x) => x <= 1.0,
+            Likelihood::Rate(x) => x > 0.0,
+        };
+        if !valid {
+            v.push(Diagnostic::error(
+                format!("invalid {} of {}", likelihood, name),
+                position,
+            ));
+        }
+    }
+    v
+}
+
+/// Skills whose success and failure probabilities sum to more than 1.
+pub fn exceeding_probabilities(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        let sum: f64 = skill
+            .successes()
+            .iter()
+            .filter_map(|x| x.likelihood())
+            .chain(skill.failures().iter().filter_map(|x| x.likelihood()))
+            .filter(|x| !x.is_rate())
+            .map(|x| x.value())
+            .sum();
+        if sum > 1.0 + 1e-9 {
+            v.push(Diagnostic::error(
+                format!(
+                    "probabilities of the modes of skill {} sum to {}",
+                    skill, sum
+                ),
+                skill.position(),
+            ));
+        }
+    }
+    v
+}
+
+/// Probabilities in a skillset with rates, that are ignored by the continuous
+/// time exports.
+pub fn mixed_likelihoods(skillset: &Skillset) -> Vec<Diagnostic> {
+    let annotations = annotations(skillset);
+    if !annotations.iter().any(|(_, x, _)| x.is_rate()) {
+        return Vec::new();
+    }
+    annotations
+        .into_iter()
+        .filter(|(_, x, _)| !x.is_rate())
+        .map(|(name, _, position)| {
+            Diagnostic::warning(
+                format!("probability of {} ignored with rates", name),
+                position,
+            )
+        })
+        .collect()
+}
+
+pub fn likelihood_analysis(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = invalid_likelihoods(skillset);
+    v.extend(exceeding_probabilities(skillset));
+    v.extend(mixed_likelihoods(skillset));
+    v
+}
@@ -0,0 +1,79 @@
+pub mod diagnostic;
+pub use diagnostic::*;
+
+pub mod configuration;
+pub use configuration::*;
+
+pub mod state_space;
+pub use state_space::*;
+
+pub mod deadlock;
+pub use deadlock::*;
+
+pub mod resource;
+pub use resource::*;
+
+pub mod invariant;
+pub use invariant::*;
+
+pub mod postcondition;
+pub use postcondition::*;
+
+pub mod constraint;
+pub use constraint::*;
+
+pub mod likelihood;
+pub use likelihood::*;
+
+pub mod ownership;
+pub use ownership::*;
+
+pub mod table;
+pub use table::*;
+
+pub mod interference;
+pub use interference::*;
+
+pub mod concurrency;
+pub use concurrency::*;
+
+pub mod symbolic;
+pub use symbolic::*;
+
+use crate::model::Skillset;
+use crate::symbolic::SymbolicSpace;
+
+pub fn analyze(skillset: &Skillset) -> Vec<Diagnostic> {
+    let space = StateSpace::explore(skillset);
+    info!("{} reachable configurations", space.len());
+    let mut v = deadlock_analysis(skillset, &space);
+    v.extend(resource_analysis(skillset, &space));
+    v.extend(invariant_analysis(skillset, &space));
+    v.extend(violated_constraints(skillset, &space));
+    v.extend(static_analysis(skillset));
+    v
+}
+
+pub fn analyze_symbolic(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut space = SymbolicSpace::explore(skillset);
+    info!(
+        "{} reachable configurations",
+        space.count(space.reachable())
+    );
+    let mut v = symbolic_analysis(skillset, &mut space);
+    v.extend(invariant_effects(skillset));
+    v.extend(invariant_outcomes(skillset));
+    v.extend(redundant_invariants(skillset));
+    v.extend(static_analysis(skillset));
+    v
+}
+
+/// The analyses that do not explore the configurations.
+fn static_analysis(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = postcondition_analysis(skillset);
+    v.extend(unsatisfiable_constraints(skillset));
+    v.extend(concurrency_conflicts(skillset));
+    v.extend(likelihood_analysis(skillset));
+    v.extend(ownership_analysis(skillset));
+    v
+}
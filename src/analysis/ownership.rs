@@ -0,0 +1,89 @@
+use super::*;
+use crate::model::*;
+
+// A resource declared @extern is changed only by the environment through the
+// events, a resource declared @internal only by the skills.
+
+/// Resources declared both extern and internal.
+pub fn ambiguous_ownerships(skillset: &Skillset) -> Vec<Diagnostic> {
+    skillset
+        .resources()
+        .iter()
+        .filter(|x| x.is_extern() && x.is_internal())
+        .map(|x| {
+            Diagnostic::error(
+                format!("resource {} is both extern and internal", x),
+                x.position(),
+            )
+        })
+        .collect()
+}
+
+/// Events writing internal resources.
+pub fn event_ownership_violations(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for event in skillset.events() {
+        for effect in event.effects() {
+            let resource = skillset.get(effect.resource().resolved()).unwrap();
+            if resource.is_internal() {
+                v.push(Diagnostic::error(
+                    format!("event {} writes internal resource {}", event, resource),
+                    effect.position(),
+                ));
+            }
+        }
+    }
+    v
+}
+
+fn skill_effects(skill: &Skill) -> Vec<(String, &Effect)> {
+    let mut v = Vec::new();
+    for x in skill.start() {
+        v.push((format!("start of skill {}", skill), x));
+    }
+    for invariant in skill.invariants() {
+        for x in invariant.effects() {
+            v.push((format!("invariant {} of skill {}", invariant, skill), x));
+        }
+    }
+    if let Some(interrupt) = skill.interrupt() {
+        for x in interrupt.effects() {
+            v.push((format!("interrupt of skill {}", skill), x));
+        }
+    }
+    for success in skill.successes() {
+        for x in success.effects() {
+            v.push((format!("success {} of skill {}", success, skill), x));
+        }
+    }
+    for failure in skill.failures() {
+        for x in failure.effects() {
+            v.push((format!("failure {} of skill {}", failure, skill), x));
+        }
+    }
+    v
+}
+
+/// Skills writing extern resources.
+pub fn skill_ownership_violations(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        for (name, effect) in skill_effects(skill) {
+            let resource = skillset.get(effect.resource().resolved()).unwrap();
+            if resource.is_extern() {
+                v.push(Diagnostic::error(
+                    format!("{} writes extern resource {}", name, resource),
+                    effect.position(),
+                ));
+            }
+        }
+    }
+    v
+}
+
+pub fn ownership_analysis(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = ambiguous_ownerships(skillset);
+    v.extend(event_ownership_violations(skillset));
+    v.extend(skill_ownership_violations(skillset));
+    v
+}
@@ -0,0 +1,175 @@
+use super::*;
+use crate::model::*;
+use crate::parser::Position;
+
+struct Mode<'a> {
+    name: String,
+    postconditions: &'a [Postcondition],
+    effects: &'a [Effect],
+    position: Option<Position>,
+    action: Action,
+}
+
+fn modes(skill: &Skill) -> Vec<Mode<'_>> {
+    let mut v = Vec::new();
+    if let Some(interrupt) = skill.interrupt() {
+        v.push(Mode {
+            name: format!("interrupt of skill {}", skill),
+            postconditions: interrupt.postconditions(),
+            effects: interrupt.effects(),
+            position: interrupt.position(),
+            action: Action::Interrupt(skill.id()),
+        });
+    }
+    for x in skill.successes() {
+        v.push(Mode {
+            name: format!("success {} of skill {}", x, skill),
+            postconditions: x.postconditions(),
+            effects: x.effects(),
+            position: x.position(),
+            action: Action::Success(x.id()),
+        });
+    }
+    for x in skill.failures() {
+        v.push(Mode {
+            name: format!("failure {} of skill {}", x, skill),
+            postconditions: x.postconditions(),
+            effects: x.effects(),
+            position: x.position(),
+            action: Action::Failure(x.id()),
+        });
+    }
+    v
+}
+
+fn invariants(skill: &Skill) -> Expr {
+    Expr::and_all(skill.invariants().iter().map(|x| x.guard().clone()))
+}
+
+fn postconditions(postconditions: &[Postcondition]) -> Expr {
+    Expr::and_all(postconditions.iter().map(|x| x.expr().clone()))
+}
+
+/// Postconditions that cannot hold while the invariants of the skill hold.
+pub fn unsatisfiable_postconditions(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        let invariants = invariants(skill);
+        for mode in modes(skill) {
+            let guard = Expr::And(
+                Box::new(invariants.clone()),
+                Box::new(postconditions(mode.postconditions)),
+            );
+            if guard.is_satisfiable(skillset) {
+                continue;
+            }
+            // blame the first postcondition that contradicts the invariants
+            let position = mode
+                .postconditions
+                .iter()
+                .find(|x| {
+                    !Expr::And(Box::new(invariants.clone()), Box::new(x.expr().clone()))
+                        .is_satisfiable(skillset)
+                })
+                .and_then(|x| x.position())
+                .or(mode.position);
+            v.push(Diagnostic::error(
+                format!(
+                    "postconditions of {} cannot hold with the invariants",
+                    mode.name
+                ),
+                position,
+            ));
+        }
+    }
+    v
+}
+
+/// Modes whose postconditions contradict their effects: wherever the
+/// postconditions and the invariants hold, some effect is not a transition.
+pub fn contradicting_effects(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        for mode in modes(skill) {
+            if mode.postconditions.is_empty() {
+                continue;
+            }
+            // unsatisfiable postconditions are already reported
+            let guard = mode.action.guard(skillset);
+            if !guard.is_satisfiable(skillset) || declared_effects(skillset, &guard, mode.effects) {
+                continue;
+            }
+            let (i, src) = undeclared_effects(skillset, &guard, mode.effects)[0];
+            let effect = &mode.effects[i];
+            v.push(Diagnostic::error(
+                format!(
+                    "postconditions of {} contradict its effects: {} is not a transition from {}",
+                    mode.name,
+                    effect.to_lang(skillset),
+                    skillset.get(src).unwrap()
+                ),
+                effect.position(),
+            ));
+        }
+    }
+    v
+}
+
+/// The states the effects bring their resources in.
+fn targets(effects: &[Effect]) -> Vec<(ResourceId, StateId)> {
+    let mut v: Vec<(ResourceId, StateId)> = Vec::new();
+    for effect in effects {
+        let resource = effect.resource().resolved();
+        v.retain(|(id, _)| *id != resource);
+        v.push((resource, effect.state().resolved()));
+    }
+    v.sort_by_key(|(id, _)| id.index());
+    v
+}
+
+/// Success and failure modes of a skill that declare postconditions, and
+/// that can both terminate it from the same state with different effects,
+/// their guards being evaluated before the effects.
+pub fn ambiguous_modes(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        let modes: Vec<Mode> = modes(skill)
+            .into_iter()
+            .filter(|x| !x.postconditions.is_empty())
+            .collect();
+        let successes = modes
+            .iter()
+            .filter(|x| matches!(x.action, Action::Success(_)));
+        for success in successes {
+            let failures = modes
+                .iter()
+                .filter(|x| matches!(x.action, Action::Failure(_)));
+            for failure in failures {
+                if targets(success.effects) == targets(failure.effects) {
+                    continue;
+                }
+                let overlap = Expr::And(
+                    Box::new(success.action.guard(skillset)),
+                    Box::new(failure.action.guard(skillset)),
+                );
+                if overlap.is_satisfiable(skillset) {
+                    v.push(Diagnostic::warning(
+                        format!(
+                            "postconditions of {} and {} overlap, the outcome is ambiguous",
+                            success.name, failure.name
+                        ),
+                        failure.position.clone(),
+                    ));
+                }
+            }
+        }
+    }
+    v
+}
+
+pub fn postcondition_analysis(skillset: &Skillset) -> Vec<Diagnostic> {
+    let mut v = unsatisfiable_postconditions(skillset);
+    v.extend(contradicting_effects(skillset));
+    v.extend(ambiguous_modes(skillset));
+    v
+}
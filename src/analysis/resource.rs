@@ -0,0 +1,175 @@
+use super::*;
+use crate::model::*;
+use std::collections::HashSet;
+
+/// States of the resources that appear in no reachable configuration.
+pub fn unreached_states(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let reached: HashSet<StateId> = space
+        .configurations()
+        .iter()
+        .flat_map(|x| x.valuation().states().iter().cloned())
+        .collect();
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        for state in resource.states() {
+            if !reached.contains(&state.id()) {
+                v.push(Diagnostic::warning(
+                    format!("state {} of resource {} is never reached", state, resource),
+                    state.position(),
+                ));
+            }
+        }
+    }
+    v
+}
+
+/// The transitions performed by the effects of the reachable actions, with
+/// the effect and the configuration where the action is taken.
+fn performed<'a>(
+    skillset: &'a Skillset,
+    space: &StateSpace,
+) -> Vec<(StateId, StateId, &'a Effect, usize, Action)> {
+    let mut v = Vec::new();
+    for i in 0..space.len() {
+        for (action, _) in space.successors(i).iter() {
+            let mut valuation = space.configuration(i).valuation().clone();
+            for effect in action.effects(skillset) {
+                let dst = effect.state().resolved();
+                let src = valuation.get(dst.resource());
+                if src != dst {
+                    v.push((src, dst, effect, i, *action));
+                }
+                valuation.set(dst);
+            }
+        }
+    }
+    v
+}
+
+/// Declared transitions that no reachable effect performs.
+pub fn dead_transitions(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let used: HashSet<(StateId, StateId)> = performed(skillset, space)
+        .into_iter()
+        .map(|(src, dst, _, _, _)| (src, dst))
+        .collect();
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        if let Transitions::List(transitions) = resource.transitions() {
+            for transition in transitions {
+                let src = transition.src().resolved();
+                let dst = transition.dst().resolved();
+                if !used.contains(&(src, dst)) {
+                    v.push(Diagnostic::warning(
+                        format!(
+                            "transition {} of resource {} is never used",
+                            transition.to_lang(skillset),
+                            resource
+                        ),
+                        transition.position(),
+                    ));
+                }
+            }
+        }
+    }
+    v
+}
+
+/// Reachable effects that perform a transition the resource does not declare.
+pub fn undeclared_transitions(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    let mut reported = HashSet::new();
+    for (src, dst, effect, i, action) in performed(skillset, space) {
+        let resource = skillset.get(src.resource()).unwrap();
+        if resource.has_transition(src, dst) || !reported.insert((src, dst, effect.position())) {
+            continue;
+        }
+        let mut witness = space.witness(i);
+        witness.push(action);
+        v.push(
+            Diagnostic::warning(
+                format!(
+                    "effect {} of {} performs undeclared transition {} -> {}",
+                    effect.to_lang(skillset),
+                    action.to_lang(skillset),
+                    skillset.get(src).unwrap(),
+                    skillset.get(dst).unwrap()
+                ),
+                effect.position(),
+            )
+            .with_witness(witness),
+        );
+    }
+    v
+}
+
+/// The resources of the condition and the effects.
+fn effect_resources(condition: &Expr, effects: &[Effect]) -> Vec<ResourceId> {
+    let mut resources = condition.resources();
+    for effect in effects {
+        let id = effect.resource().resolved();
+        if !resources.contains(&id) {
+            resources.push(id);
+        }
+    }
+    resources
+}
+
+/// The effects that do not perform a declared transition when applied in
+/// order from the valuation, with the state they leave.
+fn undeclared_from(
+    skillset: &Skillset,
+    valuation: Valuation,
+    effects: &[Effect],
+) -> Vec<(usize, StateId)> {
+    let mut v = Vec::new();
+    let mut current = valuation;
+    for (i, effect) in effects.iter().enumerate() {
+        let dst = effect.state().resolved();
+        let src = current.get(dst.resource());
+        let resource = skillset.get(dst.resource()).unwrap();
+        if src != dst && !resource.has_transition(src, dst) {
+            v.push((i, src));
+        }
+        current.set(dst);
+    }
+    v
+}
+
+/// The effects that do not perform a declared transition from some valuation
+/// satisfying the condition, with the state they leave.
+pub fn undeclared_effects(
+    skillset: &Skillset,
+    condition: &Expr,
+    effects: &[Effect],
+) -> Vec<(usize, StateId)> {
+    let resources = effect_resources(condition, effects);
+    let mut v: Vec<(usize, StateId)> = Vec::new();
+    for valuation in Valuation::all_on(skillset, &resources) {
+        if !condition.eval(&valuation) {
+            continue;
+        }
+        for (i, src) in undeclared_from(skillset, valuation, effects) {
+            if !v.iter().any(|(k, _)| *k == i) {
+                v.push((i, src));
+            }
+        }
+    }
+    v.sort_by_key(|(i, _)| *i);
+    v
+}
+
+/// Whether the effects perform declared transitions from some valuation
+/// satisfying the condition.
+pub fn declared_effects(skillset: &Skillset, condition: &Expr, effects: &[Effect]) -> bool {
+    let resources = effect_resources(condition, effects);
+    Valuation::all_on(skillset, &resources)
+        .into_iter()
+        .any(|x| condition.eval(&x) && undeclared_from(skillset, x, effects).is_empty())
+}
+
+pub fn resource_analysis(skillset: &Skillset, space: &StateSpace) -> Vec<Diagnostic> {
+    let mut v = unreached_states(skillset, space);
+    v.extend(dead_transitions(skillset, space));
+    v.extend(undeclared_transitions(skillset, space));
+    v
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::model::*;
+use std::collections::{HashMap, VecDeque};
+
+/// The configurations reachable from the initial one, explored breadth first
+/// so that witnesses are shortest paths.
+#[derive(Debug)]
+pub struct StateSpace {
+    configurations: Vec<Configuration>,
+    index: HashMap<Configuration, usize>,
+    parents: Vec<Option<(usize, Action)>>,
+    successors: Vec<Vec<(Action, usize)>>,
+}
+
+impl StateSpace {
+    pub fn explore(skillset: &Skillset) -> Self {
+        Self::explore_from(skillset, Configuration::initial(skillset))
+    }
+
+    pub fn explore_from(skillset: &Skillset, initial: Configuration) -> Self {
+        let mut space = Self {
+            configurations: vec![initial.clone()],
+            index: HashMap::new(),
+            parents: vec![None],
+            successors: Vec::new(),
+        };
+        space.index.insert(initial, 0);
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(i) = queue.pop_front() {
+            let mut successors = Vec::new();
+            for (action, next) in space.configurations[i].successors(skillset) {
+                let j = match space.index.get(&next) {
+                    Some(j) => *j,
+                    None => {
+                        let j = space.configurations.len();
+                        space.configurations.push(next.clone());
+                        space.index.insert(next, j);
+                        space.parents.push(Some((i, action)));
+                        queue.push_back(j);
+                        j
+                    }
+                };
+                successors.push((action, j));
+            }
+            // configurations are dequeued in the order they are added
+            space.successors.push(successors);
+        }
+        space
+    }
+
+    pub fn len(&self) -> usize {
+        self.configurations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.configurations.is_empty()
+    }
+
+    pub fn configurations(&self) -> &Vec<Configuration> {
+        &self.configurations
+    }
+
+    pub fn configuration(&self, i: usize) -> &Configuration {
+        &self.configurations[i]
+    }
+
+    pub fn index_of(&self, configuration: &Configuration) -> Option<usize> {
+        self.index.get(configuration).cloned()
+    }
+
+    pub fn successors(&self, i: usize) -> &Vec<(Action, usize)> {
+        &self.successors[i]
+    }
+
+    pub fn predecessors(&self) -> Vec<Vec<usize>> {
+        let mut v = vec![Vec::new(); self.len()];
+        for (i, successors) in self.successors.iter().enumerate() {
+            for (_, j) in successors.iter() {
+                v[*j].push(i);
+            }
+        }
+        v
+    }
+
+    /// The closest configuration to the initial one satisfying the predicate.
+    pub fn find<F: Fn(&Configuration) -> bool>(&self, predicate: F) -> Option<usize> {
+        self.configurations.iter().position(predicate)
+    }
+
+    /// The shortest sequence of actions from the initial configuration.
+    pub fn witness(&self, i: usize) -> Vec<Action> {
+        let mut v = Vec::new();
+        let mut current = i;
+        while let Some((previous, action)) = self.parents[current] {
+            v.push(action);
+            current = previous;
+        }
+        v.reverse();
+        v
+    }
+
+    /// The configurations from which one of the targets can be reached.
+    pub fn backward_reachable(&self, predecessors: &[Vec<usize>], targets: &[usize]) -> Vec<bool> {
+        let mut reached = vec![false; self.len()];
+        let mut stack = Vec::new();
+        for i in targets {
+            if !reached[*i] {
+                reached[*i] = true;
+                stack.push(*i);
+            }
+        }
+        while let Some(i) = stack.pop() {
+            for j in predecessors[i].iter() {
+                if !reached[*j] {
+                    reached[*j] = true;
+                    stack.push(*j);
+                }
+            }
+        }
+        reached
+    }
+}
@@ -0,0 +1,253 @@
+use super::*;
+use crate::model::*;
+use crate::symbolic::*;
+use std::collections::HashSet;
+
+// The analyses of the reachable configurations computed with BDDs, for
+// skillsets too large to be explored explicitly. They report the same
+// findings as the explicit analyses, but for deadlocks: the deadlock
+// configurations are counted, and only the closest one is given with a
+// witness.
+
+pub fn symbolic_deadlocks(skillset: &Skillset, space: &mut SymbolicSpace) -> Vec<Diagnostic> {
+    let deadlocks = space.deadlocks();
+    let (configuration, witness) = match space.witness(deadlocks) {
+        Some(x) => x,
+        None => return Vec::new(),
+    };
+    let others = match space.count(deadlocks) as u64 - 1 {
+        0 => String::new(),
+        1 => " (1 other deadlock configuration)".into(),
+        n => format!(" ({} other deadlock configurations)", n),
+    };
+    vec![Diagnostic::error(
+        format!("deadlock in {}{}", configuration.to_lang(skillset), others),
+        deadlock_position(skillset, &configuration),
+    )
+    .with_witness(witness)]
+}
+
+pub fn symbolic_never_started(skillset: &Skillset, space: &mut SymbolicSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        if !space.can_occur(&Action::Start(skill.id())) {
+            v.push(Diagnostic::warning(
+                format!("skill {} can never start", skill),
+                skill.position(),
+            ));
+        }
+    }
+    v
+}
+
+pub fn symbolic_unreached_states(
+    skillset: &Skillset,
+    space: &mut SymbolicSpace,
+) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        for state in resource.states() {
+            let expr = Expr::ResourceEq(
+                Reference::Resolved(resource.id()),
+                Reference::Resolved(state.id()),
+            );
+            if space.is_reachable(&expr).is_none() {
+                v.push(Diagnostic::warning(
+                    format!("state {} of resource {} is never reached", state, resource),
+                    state.position(),
+                ));
+            }
+        }
+    }
+    v
+}
+
+/// Resource states that are reached and then never left.
+pub fn symbolic_stuck_resources(skillset: &Skillset, space: &mut SymbolicSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        if resource.states().len() < 2 {
+            continue;
+        }
+        for state in resource.states() {
+            let other = space.expr(&Expr::ResourceNe(
+                Reference::Resolved(resource.id()),
+                Reference::Resolved(state.id()),
+            ));
+            let leaving = space.backward_reachable(other);
+            let not_leaving = space.manager().not(leaving);
+            let reachable = space.reachable();
+            let stuck = space.manager().and(reachable, not_leaving);
+            if let Some((_, witness)) = space.witness(stuck) {
+                v.push(
+                    Diagnostic::warning(
+                        format!("resource {} is stuck in state {}", resource, state),
+                        state.position(),
+                    )
+                    .with_witness(witness),
+                );
+            }
+        }
+    }
+    v
+}
+
+/// The transitions performed by the effects of the actions from the
+/// reachable configurations, with the effect and the configurations where
+/// the action performs it.
+fn symbolic_performed<'a>(
+    skillset: &'a Skillset,
+    space: &mut SymbolicSpace,
+) -> Vec<(StateId, StateId, &'a Effect, Bdd, Action)> {
+    let mut v = Vec::new();
+    for action in skillset.actions() {
+        let effects = action.effects(skillset);
+        if effects.is_empty() {
+            continue;
+        }
+        let reachable = space.reachable();
+        let enabled = space.enabled(&action);
+        let enabled = space.manager().and(enabled, reachable);
+        for (i, effect) in effects.iter().enumerate() {
+            let dst = effect.state().resolved();
+            let resource = skillset.get(dst.resource()).unwrap();
+            // an earlier effect of the action sets the source state
+            let earlier = effects[..i]
+                .iter()
+                .rev()
+                .find(|x| x.resource().resolved() == resource.id());
+            for state in resource.states() {
+                let src = state.id();
+                if src == dst {
+                    continue;
+                }
+                let from = match earlier {
+                    Some(x) if x.state().resolved() == src => enabled,
+                    Some(_) => FALSE,
+                    None => {
+                        let x = space.expr(&Expr::ResourceEq(
+                            Reference::Resolved(resource.id()),
+                            Reference::Resolved(src),
+                        ));
+                        space.manager().and(enabled, x)
+                    }
+                };
+                if from != FALSE {
+                    v.push((src, dst, effect, from, action));
+                }
+            }
+        }
+    }
+    v
+}
+
+/// Declared transitions that no reachable effect performs, and reachable
+/// effects that perform a transition the resource does not declare.
+pub fn symbolic_transitions(skillset: &Skillset, space: &mut SymbolicSpace) -> Vec<Diagnostic> {
+    let performed = symbolic_performed(skillset, space);
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        if let Transitions::List(transitions) = resource.transitions() {
+            for transition in transitions {
+                let src = transition.src().resolved();
+                let dst = transition.dst().resolved();
+                if !performed.iter().any(|x| x.0 == src && x.1 == dst) {
+                    v.push(Diagnostic::warning(
+                        format!(
+                            "transition {} of resource {} is never used",
+                            transition.to_lang(skillset),
+                            resource
+                        ),
+                        transition.position(),
+                    ));
+                }
+            }
+        }
+    }
+    let mut reported = HashSet::new();
+    for (src, dst, effect, from, action) in performed {
+        let resource = skillset.get(src.resource()).unwrap();
+        if resource.has_transition(src, dst) || !reported.insert((src, dst, effect.position())) {
+            continue;
+        }
+        let (_, mut witness) = space.witness(from).unwrap();
+        witness.push(action);
+        v.push(
+            Diagnostic::warning(
+                format!(
+                    "effect {} of {} performs undeclared transition {} -> {}",
+                    effect.to_lang(skillset),
+                    action.to_lang(skillset),
+                    skillset.get(src).unwrap(),
+                    skillset.get(dst).unwrap()
+                ),
+                effect.position(),
+            )
+            .with_witness(witness),
+        );
+    }
+    v
+}
+
+pub fn symbolic_invariants_at_start(
+    skillset: &Skillset,
+    space: &mut SymbolicSpace,
+) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for skill in skillset.skills() {
+        let start = Action::Start(skill.id());
+        let reachable = space.reachable();
+        let started = space.post(&start, reachable);
+        for invariant in skill.invariants() {
+            let guard = space.expr(invariant.guard());
+            let violated = space.manager().not(guard);
+            let violated = space.manager().and(started, violated);
+            if let Some((configuration, witness)) = space.witness(violated) {
+                v.push(
+                    Diagnostic::error(
+                        format!(
+                            "invariant {} of skill {} does not hold after start in {}",
+                            invariant,
+                            skill,
+                            configuration.valuation().to_lang(skillset)
+                        ),
+                        invariant.position(),
+                    )
+                    .with_witness(witness),
+                );
+            }
+        }
+    }
+    v
+}
+
+pub fn symbolic_constraints(skillset: &Skillset, space: &mut SymbolicSpace) -> Vec<Diagnostic> {
+    let mut v = Vec::new();
+    for constraint in skillset.constraints() {
+        if let Some((configuration, witness)) = space.check(constraint.expr()) {
+            v.push(
+                Diagnostic::error(
+                    format!(
+                        "constraint {} is violated in {}",
+                        constraint,
+                        configuration.valuation().to_lang(skillset)
+                    ),
+                    constraint.position(),
+                )
+                .with_witness(witness),
+            );
+        }
+    }
+    v
+}
+
+pub fn symbolic_analysis(skillset: &Skillset, space: &mut SymbolicSpace) -> Vec<Diagnostic> {
+    let mut v = symbolic_deadlocks(skillset, space);
+    v.extend(symbolic_never_started(skillset, space));
+    v.extend(symbolic_stuck_resources(skillset, space));
+    v.extend(symbolic_unreached_states(skillset, space));
+    v.extend(symbolic_transitions(skillset, space));
+    v.extend(symbolic_invariants_at_start(skillset, space));
+    v.extend(symbolic_constraints(skillset, space));
+    v
+}
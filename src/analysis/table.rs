@@ -0,0 +1,104 @@
+/// A report as rows of cells, printed as aligned text, CSV or JSON (an array
+/// of objects keyed by the header).
+#[derive(Clone, Debug)]
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<S: Into<String>>(header: Vec<S>) -> Self {
+        Self {
+            header: header.into_iter().map(|x| x.into()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn header(&self) -> &Vec<String> {
+        &self.header
+    }
+
+    pub fn rows(&self) -> &Vec<Vec<String>> {
+        &self.rows
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut widths: Vec<usize> = self.header.iter().map(|x| x.len()).collect();
+        for row in self.rows.iter() {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(cell.len());
+            }
+        }
+        let line = |row: &Vec<String>| {
+            let cells: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(i, x)| format!("{:width$}", x, width = widths[i]))
+                .collect();
+            format!("{}\n", cells.join(" | ").trim_end())
+        };
+        let mut s = line(&self.header);
+        let rule: Vec<String> = widths.iter().map(|x| "-".repeat(*x)).collect();
+        s.push_str(&format!("{}\n", rule.join("-+-")));
+        for row in self.rows.iter() {
+            s.push_str(&line(row));
+        }
+        s
+    }
+
+    pub fn to_csv(&self) -> String {
+        let line = |row: &Vec<String>| {
+            let cells: Vec<String> = row.iter().map(|x| csv_cell(x)).collect();
+            format!("{}\n", cells.join(","))
+        };
+        let mut s = line(&self.header);
+        for row in self.rows.iter() {
+            s.push_str(&line(row));
+        }
+        s
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut s = String::from("[\n");
+        for (i, row) in self.rows.iter().enumerate() {
+            let fields: Vec<String> = self
+                .header
+                .iter()
+                .zip(row.iter())
+                .map(|(k, v)| format!("{}: {}", json_string(k), json_string(v)))
+                .collect();
+            let sep = if i + 1 < self.rows.len() { "," } else { "" };
+            s.push_str(&format!("\t{{{}}}{}\n", fields.join(", "), sep));
+        }
+        s.push_str("]\n");
+        s
+    }
+}
+
+fn csv_cell(cell: &str) -> String {
+    if cell.contains(',') || cell.contains('"') || cell.contains('\n') {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 60em; margin: auto; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; }
+code { background: #f4f4f4; }";
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn inline(x: &Inline) -> String {
+    match x {
+        Inline::Text(s) => escape(s),
+        Inline::Code(s) => format!("<code>{}</code>", escape(s)),
+        Inline::Link(s, page, anchor) => match anchor {
+            Some(anchor) => format!("<a href=\"{}.html#{}\">{}</a>", page, anchor, escape(s)),
+            None => format!("<a href=\"{}.html\">{}</a>", page, escape(s)),
+        },
+        Inline::Anchor(id) => format!("<a id=\"{}\"></a>", id),
+    }
+}
+
+fn line(line: &[Inline]) -> String {
+    line.iter().map(inline).collect()
+}
+
+/// A standalone HTML page, the links going to the pages with the .html
+/// extension.
+pub fn to_html(page: &Page) -> String {
+    let mut s = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        escape(page.title()),
+        STYLE,
+        escape(page.title())
+    );
+    for block in page.blocks() {
+        match block {
+            Block::Heading(level, l) => {
+                s.push_str(&format!("<h{}>{}</h{}>\n", level, line(l), level));
+            }
+            Block::Paragraph(l) => s.push_str(&format!("<p>{}</p>\n", line(l))),
+            Block::List(items) => {
+                s.push_str("<ul>\n");
+                for item in items {
+                    s.push_str(&format!("<li>{}</li>\n", line(item)));
+                }
+                s.push_str("</ul>\n");
+            }
+            Block::Table(header, rows) => {
+                s.push_str("<table>\n<tr>");
+                for x in header {
+                    s.push_str(&format!("<th>{}</th>", escape(x)));
+                }
+                s.push_str("</tr>\n");
+                for row in rows {
+                    s.push_str("<tr>");
+                    for x in row {
+                        s.push_str(&format!("<td>{}</td>", line(x)));
+                    }
+                    s.push_str("</tr>\n");
+                }
+                s.push_str("</table>\n");
+            }
+        }
+    }
+    s.push_str("</body>\n</html>\n");
+    s
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+fn inline(x: &Inline) -> String {
+    match x {
+        Inline::Text(s) => s.replace('|', "\\|"),
+        Inline::Code(s) => format!("`{}`", s.replace('|', "\\|")),
+        Inline::Link(s, page, anchor) => match anchor {
+            Some(anchor) => format!("[{}]({}.md#{})", s, page, anchor),
+            None => format!("[{}]({}.md)", s, page),
+        },
+        Inline::Anchor(id) => format!("<a id=\"{}\"></a>", id),
+    }
+}
+
+fn line(line: &[Inline]) -> String {
+    line.iter().map(inline).collect()
+}
+
+/// A page in Markdown, the links going to the pages with the .md extension.
+pub fn to_markdown(page: &Page) -> String {
+    let mut s = format!("# {}\n", page.title());
+    for block in page.blocks() {
+        s.push('\n');
+        match block {
+            Block::Heading(level, l) => {
+                s.push_str(&format!("{} {}\n", "#".repeat(*level), line(l)));
+            }
+            Block::Paragraph(l) => s.push_str(&format!("{}\n", line(l))),
+            Block::List(items) => {
+                for item in items {
+                    s.push_str(&format!("- {}\n", line(item)));
+                }
+            }
+            Block::Table(header, rows) => {
+                s.push_str(&format!("| {} |\n", header.join(" | ")));
+                let separator: Vec<&str> = header.iter().map(|_| "---").collect();
+                s.push_str(&format!("| {} |\n", separator.join(" | ")));
+                for row in rows {
+                    let cells: Vec<String> = row.iter().map(|x| line(x)).collect();
+                    s.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+            }
+        }
+    }
+    s
+}
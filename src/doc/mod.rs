@@ -0,0 +1,11 @@
+pub mod page;
+pub use page::*;
+
+pub mod reference;
+pub use reference::*;
+
+pub mod markdown;
+pub use markdown::*;
+
+pub mod html;
+pub use html::*;
@@ -0,0 +1,83 @@
+/// A piece of text: plain, code, a link to a page and an anchor within it,
+/// or an anchor.
+#[derive(Debug, Clone)]
+pub enum Inline {
+    Text(String),
+    Code(String),
+    Link(String, String, Option<String>),
+    Anchor(String),
+}
+
+pub type Line = Vec<Inline>;
+
+pub fn text<S: Into<String>>(s: S) -> Line {
+    vec![Inline::Text(s.into())]
+}
+
+pub fn code<S: Into<String>>(s: S) -> Line {
+    vec![Inline::Code(s.into())]
+}
+
+/// Lines separated by commas.
+pub fn joined(lines: Vec<Line>) -> Line {
+    let mut v = Vec::new();
+    for (i, line) in lines.into_iter().enumerate() {
+        if i > 0 {
+            v.push(Inline::Text(", ".into()));
+        }
+        v.extend(line);
+    }
+    v
+}
+
+#[derive(Debug, Clone)]
+pub enum Block {
+    Heading(usize, Line),
+    Paragraph(Line),
+    List(Vec<Line>),
+    Table(Vec<String>, Vec<Vec<Line>>),
+}
+
+/// A page of documentation, named after its file without extension.
+#[derive(Debug, Clone)]
+pub struct Page {
+    name: String,
+    title: String,
+    blocks: Vec<Block>,
+}
+
+impl Page {
+    pub fn new<S: Into<String>, T: Into<String>>(name: S, title: T) -> Self {
+        Self {
+            name: name.into(),
+            title: title.into(),
+            blocks: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn blocks(&self) -> &Vec<Block> {
+        &self.blocks
+    }
+
+    pub fn add(&mut self, block: Block) {
+        self.blocks.push(block);
+    }
+
+    /// A section with a heading of level 2, its content being empty when
+    /// there is nothing to document.
+    pub fn section<S: Into<String>>(&mut self, title: S, content: Option<Block>) {
+        self.add(Block::Heading(2, text(title)));
+        match content {
+            Some(block) => self.add(block),
+            None => self.add(Block::Paragraph(text("None."))),
+        }
+    }
+}
@@ -0,0 +1,459 @@
+use super::*;
+use crate::analysis::{read_resources, written_resources};
+use crate::model::*;
+use std::time::Duration;
+
+const INDEX: &str = "index";
+
+//------------------------- Links -------------------------
+
+fn skill_page(skill: &Skill) -> String {
+    format!("skill_{}", skill.name())
+}
+
+fn skill_link(skill: &Skill) -> Line {
+    vec![Inline::Link(skill.name().into(), skill_page(skill), None)]
+}
+
+fn resource_anchor(resource: &Resource) -> String {
+    format!("resource-{}", resource.name())
+}
+
+fn resource_link(resource: &Resource) -> Line {
+    vec![Inline::Link(
+        resource.name().into(),
+        INDEX.into(),
+        Some(resource_anchor(resource)),
+    )]
+}
+
+fn event_anchor(event: &Event) -> String {
+    format!("event-{}", event.name())
+}
+
+fn event_link(event: &Event) -> Line {
+    vec![Inline::Link(
+        event.name().into(),
+        INDEX.into(),
+        Some(event_anchor(event)),
+    )]
+}
+
+//------------------------- Text -------------------------
+
+/// The attributes of an element followed by its description.
+fn description(description: Option<&str>, attributes: &Attributes) -> Line {
+    let mut v = Vec::new();
+    for x in attributes.all() {
+        if !v.is_empty() {
+            v.push(Inline::Text(" ".into()));
+        }
+        v.push(Inline::Code(x.to_string()));
+    }
+    if let Some(d) = description {
+        if !v.is_empty() {
+            v.push(Inline::Text(" ".into()));
+        }
+        v.push(Inline::Text(d.into()));
+    }
+    v
+}
+
+fn period(period: Duration) -> String {
+    format!("{} ms", period.as_millis())
+}
+
+fn effect(skillset: &Skillset, effect: &Effect) -> Line {
+    let mut v = resource_link(skillset.get(effect.resource().resolved()).unwrap());
+    v.push(Inline::Text(" -> ".into()));
+    v.push(Inline::Code(effect.state().to_lang(skillset)));
+    v
+}
+
+fn effects(skillset: &Skillset, effects: &[Effect]) -> Line {
+    joined(effects.iter().map(|x| effect(skillset, x)).collect())
+}
+
+fn postconditions(skillset: &Skillset, postconditions: &[Postcondition]) -> Line {
+    joined(
+        postconditions
+            .iter()
+            .map(|x| code(x.to_lang(skillset)))
+            .collect(),
+    )
+}
+
+fn variables(skillset: &Skillset, variables: &[Variable]) -> Option<Block> {
+    if variables.is_empty() {
+        return None;
+    }
+    let rows = variables
+        .iter()
+        .map(|x| {
+            vec![
+                code(x.name()),
+                code(x.rl_type().to_lang(skillset)),
+                description(x.description(), x.attributes()),
+            ]
+        })
+        .collect();
+    Some(Block::Table(
+        vec!["Name".into(), "Type".into(), "Description".into()],
+        rows,
+    ))
+}
+
+fn terminations<I: TerminateId>(skillset: &Skillset, modes: &[Terminate<I>]) -> Option<Block>
+where
+    Terminate<I>: Named<I>,
+{
+    if modes.is_empty() {
+        return None;
+    }
+    let rows = modes
+        .iter()
+        .map(|x| {
+            vec![
+                code(x.name()),
+                postconditions(skillset, x.postconditions()),
+                effects(skillset, x.effects()),
+                description(x.description(), x.attributes()),
+            ]
+        })
+        .collect();
+    Some(Block::Table(
+        vec![
+            "Name".into(),
+            "Postconditions".into(),
+            "Effects".into(),
+            "Description".into(),
+        ],
+        rows,
+    ))
+}
+
+//------------------------- Index -------------------------
+
+fn data_table(skillset: &Skillset) -> Option<Block> {
+    if skillset.data().is_empty() {
+        return None;
+    }
+    let rows = skillset
+        .data()
+        .iter()
+        .map(|x| {
+            vec![
+                code(x.name()),
+                code(x.rl_type().to_lang(skillset)),
+                text(x.period().map(period).unwrap_or_else(|| "-".into())),
+                description(x.description(), x.attributes()),
+            ]
+        })
+        .collect();
+    Some(Block::Table(
+        vec![
+            "Name".into(),
+            "Type".into(),
+            "Period".into(),
+            "Description".into(),
+        ],
+        rows,
+    ))
+}
+
+fn resource_table(skillset: &Skillset) -> Option<Block> {
+    if skillset.resources().is_empty() {
+        return None;
+    }
+    let mut rows = Vec::new();
+    for resource in skillset.resources() {
+        let id = resource.id();
+        let mut name = vec![Inline::Anchor(resource_anchor(resource))];
+        name.push(Inline::Code(resource.name().into()));
+        let states = joined(
+            resource
+                .states()
+                .iter()
+                .map(|x| {
+                    let mut v = code(x.name());
+                    for a in x.attributes().all() {
+                        v.push(Inline::Text(" ".into()));
+                        v.push(Inline::Code(a.to_string()));
+                    }
+                    if let Some(d) = x.description() {
+                        v.push(Inline::Text(format!(" ({})", d)));
+                    }
+                    v
+                })
+                .collect(),
+        );
+        let transitions = match resource.transitions() {
+            Transitions::All => text("all"),
+            Transitions::List(l) => joined(l.iter().map(|x| code(x.to_lang(skillset))).collect()),
+        };
+        let mut readers: Vec<Line> = skillset
+            .events()
+            .iter()
+            .filter(|x| {
+                x.guard()
+                    .as_ref()
+                    .is_some_and(|g| g.resources().contains(&id))
+            })
+            .map(event_link)
+            .collect();
+        readers.extend(
+            skillset
+                .skills()
+                .iter()
+                .filter(|x| read_resources(x).contains(&id))
+                .map(skill_link),
+        );
+        let mut writers: Vec<Line> = skillset
+            .events()
+            .iter()
+            .filter(|x| x.effects().iter().any(|e| e.resource().resolved() == id))
+            .map(event_link)
+            .collect();
+        writers.extend(
+            skillset
+                .skills()
+                .iter()
+                .filter(|x| written_resources(x).contains(&id))
+                .map(skill_link),
+        );
+        rows.push(vec![
+            name,
+            states,
+            code(skillset.get(resource.initial()).unwrap().name()),
+            transitions,
+            joined(readers),
+            joined(writers),
+            description(resource.description(), resource.attributes()),
+        ]);
+    }
+    Some(Block::Table(
+        vec![
+            "Name".into(),
+            "States".into(),
+            "Initial".into(),
+            "Transitions".into(),
+            "Read by".into(),
+            "Written by".into(),
+            "Description".into(),
+        ],
+        rows,
+    ))
+}
+
+fn constraint_table(skillset: &Skillset) -> Option<Block> {
+    if skillset.constraints().is_empty() {
+        return None;
+    }
+    let rows = skillset
+        .constraints()
+        .iter()
+        .map(|x| {
+            vec![
+                code(x.name()),
+                code(x.expr().to_lang(skillset)),
+                description(None, x.attributes()),
+            ]
+        })
+        .collect();
+    Some(Block::Table(
+        vec!["Name".into(), "Condition".into(), "Attributes".into()],
+        rows,
+    ))
+}
+
+fn event_table(skillset: &Skillset) -> Option<Block> {
+    if skillset.events().is_empty() {
+        return None;
+    }
+    let rows = skillset
+        .events()
+        .iter()
+        .map(|x| {
+            let mut name = vec![Inline::Anchor(event_anchor(x))];
+            name.push(Inline::Code(x.name().into()));
+            let guard = match x.guard() {
+                Some(guard) => code(guard.to_lang(skillset)),
+                None => text("-"),
+            };
+            vec![
+                name,
+                guard,
+                effects(skillset, x.effects()),
+                description(x.description(), x.attributes()),
+            ]
+        })
+        .collect();
+    Some(Block::Table(
+        vec![
+            "Name".into(),
+            "Guard".into(),
+            "Effects".into(),
+            "Description".into(),
+        ],
+        rows,
+    ))
+}
+
+fn skill_table(skillset: &Skillset) -> Option<Block> {
+    if skillset.skills().is_empty() {
+        return None;
+    }
+    let rows = skillset
+        .skills()
+        .iter()
+        .map(|x| {
+            let names = |v: &[Variable]| joined(v.iter().map(|x| code(x.name())).collect());
+            vec![
+                skill_link(x),
+                names(x.inputs()),
+                names(x.outputs()),
+                description(x.description(), x.attributes()),
+            ]
+        })
+        .collect();
+    Some(Block::Table(
+        vec![
+            "Name".into(),
+            "Inputs".into(),
+            "Outputs".into(),
+            "Description".into(),
+        ],
+        rows,
+    ))
+}
+
+fn index(skillset: &Skillset) -> Page {
+    let mut page = Page::new(INDEX, format!("Skillset {}", skillset.name()));
+    page.section("Skills", skill_table(skillset));
+    page.section("Resources", resource_table(skillset));
+    page.section("Constraints", constraint_table(skillset));
+    page.section("Events", event_table(skillset));
+    page.section("Data", data_table(skillset));
+    page
+}
+
+//------------------------- Skill -------------------------
+
+fn skill(skillset: &Skillset, skill: &Skill) -> Page {
+    let mut page = Page::new(skill_page(skill), format!("Skill {}", skill.name()));
+    page.add(Block::Paragraph(vec![
+        Inline::Text("Skill of the skillset ".into()),
+        Inline::Link(skillset.name().into(), INDEX.into(), None),
+        Inline::Text(".".into()),
+    ]));
+    if skill.description().is_some() || !skill.attributes().is_empty() {
+        page.add(Block::Paragraph(description(
+            skill.description(),
+            skill.attributes(),
+        )));
+    }
+    page.section("Inputs", variables(skillset, skill.inputs()));
+    page.section("Outputs", variables(skillset, skill.outputs()));
+    // Preconditions
+    let preconditions = if skill.preconditions().is_empty() {
+        None
+    } else {
+        let rows = skill
+            .preconditions()
+            .iter()
+            .map(|x| vec![code(x.name()), code(x.expr().to_lang(skillset))])
+            .collect();
+        Some(Block::Table(vec!["Name".into(), "Condition".into()], rows))
+    };
+    page.section("Preconditions", preconditions);
+    // Start
+    let start = if skill.start().is_empty() {
+        None
+    } else {
+        Some(Block::List(
+            skill.start().iter().map(|x| effect(skillset, x)).collect(),
+        ))
+    };
+    page.section("Start", start);
+    // Invariants
+    let invariants = if skill.invariants().is_empty() {
+        None
+    } else {
+        let rows = skill
+            .invariants()
+            .iter()
+            .map(|x| {
+                vec![
+                    code(x.name()),
+                    code(x.guard().to_lang(skillset)),
+                    effects(skillset, x.effects()),
+                ]
+            })
+            .collect();
+        Some(Block::Table(
+            vec!["Name".into(), "Guard".into(), "Recovery effects".into()],
+            rows,
+        ))
+    };
+    page.section("Invariants", invariants);
+    // Progress
+    let progress = skill.progress().as_ref().map(|x| {
+        let message = x
+            .message()
+            .iter()
+            .map(|v| code(format!("{}: {}", v.name(), v.rl_type().to_lang(skillset))))
+            .collect();
+        let mut message_line = text("message: ");
+        message_line.extend(joined(message));
+        Block::List(vec![
+            text(format!("period: {}", period(x.period()))),
+            message_line,
+        ])
+    });
+    page.section("Progress", progress);
+    // Interrupt
+    let interrupt = skill.interrupt().as_ref().map(|x| {
+        let mut post = text("postconditions: ");
+        post.extend(postconditions(skillset, x.postconditions()));
+        let mut eff = text("effects: ");
+        eff.extend(effects(skillset, x.effects()));
+        let mut v = Vec::new();
+        let d = description(x.description(), x.attributes());
+        if !d.is_empty() {
+            v.push(d);
+        }
+        v.push(text(format!("interrupting: {}", x.interrupting())));
+        v.push(post);
+        v.push(eff);
+        Block::List(v)
+    });
+    page.section("Interrupt", interrupt);
+    page.section("Success", terminations(skillset, skill.successes()));
+    page.section("Failure", terminations(skillset, skill.failures()));
+    // Resources
+    let resources = |v: Vec<ResourceId>| -> Line {
+        joined(
+            v.iter()
+                .map(|x| resource_link(skillset.get(*x).unwrap()))
+                .collect(),
+        )
+    };
+    let mut reads = text("reads: ");
+    reads.extend(resources(read_resources(skill)));
+    let mut writes = text("writes: ");
+    writes.extend(resources(written_resources(skill)));
+    page.section("Resources", Some(Block::List(vec![reads, writes])));
+    page
+}
+
+//------------------------- Skillset -------------------------
+
+/// The pages documenting a resolved skillset: an index with the skills,
+/// resources, events and data, and a page per skill.
+pub fn documentation(skillset: &Skillset) -> Vec<Page> {
+    let mut v = vec![index(skillset)];
+    for x in skillset.skills() {
+        v.push(skill(skillset, x));
+    }
+    v
+}
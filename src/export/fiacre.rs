@@ -0,0 +1,336 @@
+use crate::model::*;
+
+// Translation:
+// - each resource R is a shared variable of type R_t, whose constructors are
+//   named R_State;
+// - each resource R has a process R_events with the events whose first
+//   effect writes R (events without effect do not change the state and are
+//   not translated);
+// - each skill S has a process S with states idle and running;
+// - each data D with a period has a process D_data refreshing it;
+// - every transition is labelled by a port named after the action
+//   (see Action::name), so that Tina traces can be read back in terms of
+//   events, skills and modes;
+// - periods are translated into time intervals in milliseconds.
+
+//------------------------- Naming -------------------------
+
+fn type_name(resource: &Resource) -> String {
+    format!("{}_t", resource.name())
+}
+
+fn state_name(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!("{}_{}", resource.name(), skillset.get(id).unwrap().name())
+}
+
+//------------------------- Expr -------------------------
+
+pub fn fiacre_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "true".into(),
+        Expr::False => "false".into(),
+        Expr::ResourceEq(resource, state) => format!(
+            "({} = {})",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::ResourceNe(resource, state) => format!(
+            "({} <> {})",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::Not(e) => format!("(not {})", fiacre_expr(skillset, e)),
+        Expr::And(l, r) => format!(
+            "({} and {})",
+            fiacre_expr(skillset, l),
+            fiacre_expr(skillset, r)
+        ),
+        Expr::Or(l, r) => format!(
+            "({} or {})",
+            fiacre_expr(skillset, l),
+            fiacre_expr(skillset, r)
+        ),
+        Expr::Implies(l, r) => format!(
+            "((not {}) or {})",
+            fiacre_expr(skillset, l),
+            fiacre_expr(skillset, r)
+        ),
+    }
+}
+
+//------------------------- Process -------------------------
+
+fn shared_parameters(skillset: &Skillset) -> String {
+    skillset
+        .resources()
+        .iter()
+        .map(|x| format!("&{} : {}", x.name(), type_name(x)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn shared_arguments(skillset: &Skillset) -> String {
+    skillset
+        .resources()
+        .iter()
+        .map(|x| format!("&{}", x.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn ports(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|x| format!("{} : none", x))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn transition(skillset: &Skillset, action: &Action, target: &str) -> String {
+    let mut s = format!(
+        "on {}; {}; ",
+        fiacre_expr(skillset, &action.guard(skillset)),
+        action.name(skillset)
+    );
+    for effect in action.effects(skillset) {
+        s.push_str(&format!(
+            "{} := {}; ",
+            effect.resource().to_lang(skillset),
+            state_name(skillset, effect.state().resolved())
+        ));
+    }
+    s.push_str(&format!("to {}", target));
+    s
+}
+
+fn select(branches: &[String]) -> String {
+    match branches {
+        [branch] => format!("\t\t{}\n", branch),
+        _ => {
+            let mut s = String::from("\t\tselect\n");
+            for (i, x) in branches.iter().enumerate() {
+                if i == 0 {
+                    s.push_str(&format!("\t\t\t{}\n", x));
+                } else {
+                    s.push_str(&format!("\t\t[]\t{}\n", x));
+                }
+            }
+            s.push_str("\t\tend\n");
+            s
+        }
+    }
+}
+
+fn resource_process(skillset: &Skillset, resource: &Resource) -> Option<(String, Vec<String>)> {
+    let events: Vec<Action> = skillset
+        .events()
+        .iter()
+        .filter(|x| match x.effects().first() {
+            Some(effect) => effect.resource().resolved() == resource.id(),
+            None => false,
+        })
+        .map(|x| Action::Event(x.id()))
+        .collect();
+    if events.is_empty() {
+        return None;
+    }
+    let names: Vec<String> = events.iter().map(|x| x.name(skillset)).collect();
+    let mut s = format!(
+        "process {}_events [{}] ({}) is\n",
+        resource.name(),
+        ports(&names),
+        shared_parameters(skillset)
+    );
+    s.push_str("\tstates s\n\tfrom s\n");
+    let branches: Vec<String> = events
+        .iter()
+        .map(|x| transition(skillset, x, "s"))
+        .collect();
+    s.push_str(&select(&branches));
+    s.push('\n');
+    Some((s, names))
+}
+
+fn skill_process(skillset: &Skillset, skill: &Skill) -> (String, Vec<String>) {
+    let actions: Vec<Action> = skillset
+        .actions()
+        .into_iter()
+        .filter(|x| x.skill() == Some(skill.id()))
+        .collect();
+    let mut names: Vec<String> = actions.iter().map(|x| x.name(skillset)).collect();
+    if skill.progress().is_some() {
+        names.push(format!("{}_progress", skill.name()));
+    }
+    let mut s = format!(
+        "process {} [{}] ({}) is\n",
+        skill.name(),
+        ports(&names),
+        shared_parameters(skillset)
+    );
+    s.push_str("\tstates idle, running\n");
+    // Idle
+    s.push_str("\tfrom idle\n");
+    s.push_str(&format!(
+        "\t\t{}\n",
+        transition(skillset, &Action::Start(skill.id()), "running")
+    ));
+    // Running
+    s.push_str("\tfrom running\n");
+    let mut branches: Vec<String> = actions
+        .iter()
+        .filter(|x| !matches!(x, Action::Start(_)))
+        .map(|x| transition(skillset, x, "idle"))
+        .collect();
+    if let Some(progress) = skill.progress() {
+        let period = progress.period().as_millis();
+        branches.push(format!(
+            "wait [{}, {}]; {}_progress; to running",
+            period,
+            period,
+            skill.name()
+        ));
+    }
+    s.push_str(&select(&branches));
+    s.push('\n');
+    (s, names)
+}
+
+fn data_process(data: &Data) -> Option<(String, Vec<String>)> {
+    let period = data.period()?.as_millis();
+    let port = format!("{}_update", data.name());
+    let mut s = format!(
+        "process {}_data [{}] is\n",
+        data.name(),
+        ports(std::slice::from_ref(&port))
+    );
+    s.push_str("\tstates s\n\tfrom s\n");
+    s.push_str(&format!(
+        "\t\twait [{}, {}]; {}; to s\n\n",
+        period, period, port
+    ));
+    Some((s, vec![port]))
+}
+
+//------------------------- Skillset -------------------------
+
+/// Translate a resolved skillset into a Fiacre specification for Tina.
+pub fn to_fiacre(skillset: &Skillset) -> String {
+    let mut s = format!("/* skillset {} */\n\n", skillset.name());
+    // Ports
+    s.push_str("/* ports:\n");
+    for action in skillset.actions() {
+        s.push_str(&format!(
+            "   {}: {}\n",
+            action.name(skillset),
+            action.to_lang(skillset)
+        ));
+    }
+    for skill in skillset.skills() {
+        if skill.progress().is_some() {
+            s.push_str(&format!(
+                "   {}_progress: progress {}\n",
+                skill.name(),
+                skill
+            ));
+        }
+    }
+    for data in skillset.data() {
+        if data.period().is_some() {
+            s.push_str(&format!("   {}_update: data {}\n", data.name(), data));
+        }
+    }
+    s.push_str("*/\n\n");
+    // Types
+    for resource in skillset.resources() {
+        let states: Vec<String> = resource
+            .states()
+            .iter()
+            .map(|x| state_name(skillset, x.id()))
+            .collect();
+        s.push_str(&format!(
+            "type {} is union {} end\n",
+            type_name(resource),
+            states.join(" | ")
+        ));
+    }
+    s.push('\n');
+    // Processes
+    let mut instances: Vec<String> = Vec::new();
+    for resource in skillset.resources() {
+        if let Some((text, names)) = resource_process(skillset, resource) {
+            s.push_str(&text);
+            instances.push(format!(
+                "{}_events [{}] ({})",
+                resource.name(),
+                names.join(", "),
+                shared_arguments(skillset)
+            ));
+        }
+    }
+    for skill in skillset.skills() {
+        let (text, names) = skill_process(skillset, skill);
+        s.push_str(&text);
+        instances.push(format!(
+            "{} [{}] ({})",
+            skill.name(),
+            names.join(", "),
+            shared_arguments(skillset)
+        ));
+    }
+    for data in skillset.data() {
+        if let Some((text, names)) = data_process(data) {
+            s.push_str(&text);
+            instances.push(format!("{}_data [{}]", data.name(), names.join(", ")));
+        }
+    }
+    // Component
+    s.push_str(&format!("component {}_skillset is\n", skillset.name()));
+    if !skillset.resources().is_empty() {
+        let vars: Vec<String> = skillset
+            .resources()
+            .iter()
+            .map(|x| {
+                format!(
+                    "{} : {} := {}",
+                    x.name(),
+                    type_name(x),
+                    state_name(skillset, x.initial())
+                )
+            })
+            .collect();
+        s.push_str(&format!("\tvar {}\n", vars.join(",\n\t\t")));
+    }
+    let mut all_ports = Vec::new();
+    for action in skillset.actions() {
+        all_ports.push(action.name(skillset));
+    }
+    for skill in skillset.skills() {
+        if skill.progress().is_some() {
+            all_ports.push(format!("{}_progress", skill.name()));
+        }
+    }
+    for data in skillset.data() {
+        if data.period().is_some() {
+            all_ports.push(format!("{}_update", data.name()));
+        }
+    }
+    if !all_ports.is_empty() {
+        s.push_str(&format!("\tport {}\n", ports(&all_ports)));
+    }
+    if instances.is_empty() {
+        s.push_str("\tnull\n");
+    } else {
+        s.push_str("\tpar\n");
+        for (i, x) in instances.iter().enumerate() {
+            if i == 0 {
+                s.push_str(&format!("\t\t   {}\n", x));
+            } else {
+                s.push_str(&format!("\t\t|| {}\n", x));
+            }
+        }
+        s.push_str("\tend\n");
+    }
+    s.push_str(&format!("\n{}_skillset\n", skillset.name()));
+    s
+}
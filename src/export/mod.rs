@@ -0,0 +1,23 @@
+pub mod smv;
+pub use smv::*;
+
+pub mod fiacre;
+pub use fiacre::*;
+
+pub mod uppaal;
+pub use uppaal::*;
+
+pub mod pddl;
+pub use pddl::*;
+
+pub mod smtlib;
+pub use smtlib::*;
+
+pub mod promela;
+pub use promela::*;
+
+pub mod tla;
+pub use tla::*;
+
+pub mod prism;
+pub use prism::*;
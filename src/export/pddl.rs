@@ -0,0 +1,162 @@
+use crate::model::*;
+
+// Translation:
+// - each state S of a resource R is a predicate (R_S), exactly one of them
+//   holds for each resource;
+// - each success mode of a skill is an action: preconditions hold, and the
+//   invariants hold after the start effects and after the states of the
+//   postconditions the skill is assumed to reach (see Action::terminations);
+//   the start effects, these states, then the effects of the mode are
+//   applied; the action is named after the skill when it has a single
+//   success mode, and S_mode otherwise, suffixed by the number of the
+//   termination when the postconditions allow several states;
+// - events are exogenous actions named event_E.
+
+//------------------------- Naming -------------------------
+
+fn predicate(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!("({}_{})", resource.name(), skillset.get(id).unwrap().name())
+}
+
+//------------------------- Expr -------------------------
+
+pub fn pddl_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "(and)".into(),
+        Expr::False => "(or)".into(),
+        Expr::ResourceEq(_, state) => predicate(skillset, state.resolved()),
+        Expr::ResourceNe(_, state) => format!("(not {})", predicate(skillset, state.resolved())),
+        Expr::Not(e) => format!("(not {})", pddl_expr(skillset, e)),
+        Expr::And(l, r) => format!(
+            "(and {} {})",
+            pddl_expr(skillset, l),
+            pddl_expr(skillset, r)
+        ),
+        Expr::Or(l, r) => format!("(or {} {})", pddl_expr(skillset, l), pddl_expr(skillset, r)),
+        Expr::Implies(l, r) => format!(
+            "(imply {} {})",
+            pddl_expr(skillset, l),
+            pddl_expr(skillset, r)
+        ),
+    }
+}
+
+fn pddl_effects(skillset: &Skillset, effects: &[Effect]) -> String {
+    let mut v = Vec::new();
+    for (i, effect) in effects.iter().enumerate() {
+        let id = effect.state().resolved();
+        // a later effect on the same resource overrides this one
+        if effects[i + 1..]
+            .iter()
+            .any(|x| x.resource().resolved() == id.resource())
+        {
+            continue;
+        }
+        let resource = skillset.get(id.resource()).unwrap();
+        v.push(predicate(skillset, id));
+        for state in resource.states() {
+            if state.id() != id {
+                v.push(format!("(not {})", predicate(skillset, state.id())));
+            }
+        }
+    }
+    format!("(and {})", v.join(" "))
+}
+
+fn action(name: &str, comment: &str, precondition: &str, effect: &str) -> String {
+    let mut s = format!("\t; {}\n", comment);
+    s.push_str(&format!("\t(:action {}\n", name));
+    s.push_str("\t\t:parameters ()\n");
+    s.push_str(&format!("\t\t:precondition {}\n", precondition));
+    s.push_str(&format!("\t\t:effect {}\n", effect));
+    s.push_str("\t)\n");
+    s
+}
+
+//------------------------- Domain -------------------------
+
+/// Translate a resolved skillset into a PDDL domain.
+pub fn to_pddl_domain(skillset: &Skillset) -> String {
+    let mut s = format!("(define (domain {})\n", skillset.name());
+    s.push_str("\t(:requirements :strips :negative-preconditions :disjunctive-preconditions)\n");
+    // Predicates
+    s.push_str("\t(:predicates\n");
+    for resource in skillset.resources() {
+        let states: Vec<String> = resource
+            .states()
+            .iter()
+            .map(|x| predicate(skillset, x.id()))
+            .collect();
+        s.push_str(&format!("\t\t{}\n", states.join(" ")));
+    }
+    s.push_str("\t)\n");
+    // Skills
+    for skill in skillset.skills() {
+        let precondition = Expr::and_all(skill.preconditions().iter().map(|x| x.expr().clone()));
+        let invariants = Expr::and_all(skill.invariants().iter().map(|x| x.guard().clone()));
+        for success in skill.successes() {
+            let terminations = Action::Success(success.id()).terminations(skillset);
+            for (i, termination) in terminations.iter().enumerate() {
+                let mut effects = skill.start().clone();
+                effects.extend(termination.iter().cloned());
+                let guard = Expr::and_all([
+                    precondition.clone(),
+                    invariants.before(skill.start()),
+                    invariants.before(&effects),
+                ])
+                .simplified();
+                effects.extend(success.effects().iter().cloned());
+                let mut name = if skill.successes().len() == 1 {
+                    skill.name().to_string()
+                } else {
+                    format!("{}_{}", skill.name(), success.name())
+                };
+                if terminations.len() > 1 {
+                    name = format!("{}_{}", name, i + 1);
+                }
+                s.push_str(&action(
+                    &name,
+                    &format!("skill {} success {}", skill, success),
+                    &pddl_expr(skillset, &guard),
+                    &pddl_effects(skillset, &effects),
+                ));
+            }
+        }
+    }
+    // Events
+    for event in skillset.events() {
+        let action_id = Action::Event(event.id());
+        s.push_str(&action(
+            &action_id.name(skillset),
+            &format!("exogenous event {}", event),
+            &pddl_expr(skillset, &action_id.guard(skillset)),
+            &pddl_effects(skillset, event.effects()),
+        ));
+    }
+    s.push_str(")\n");
+    s
+}
+
+//------------------------- Problem -------------------------
+
+/// A PDDL problem for the domain of the skillset, from a valuation of the
+/// resources to a goal.
+pub fn to_pddl_problem(
+    skillset: &Skillset,
+    name: &str,
+    initial: &Valuation,
+    goal: &Expr,
+) -> String {
+    let mut s = format!("(define (problem {})\n", name);
+    s.push_str(&format!("\t(:domain {})\n", skillset.name()));
+    let init: Vec<String> = initial
+        .states()
+        .iter()
+        .map(|x| predicate(skillset, *x))
+        .collect();
+    s.push_str(&format!("\t(:init {})\n", init.join(" ")));
+    s.push_str(&format!("\t(:goal {})\n", pddl_expr(skillset, goal)));
+    s.push_str(")\n");
+    s
+}
@@ -0,0 +1,283 @@
+use crate::model::*;
+
+// Translation:
+// - the states of each resource R are the constants R_S, R being a bounded
+//   integer variable, each skill S has a boolean S_running;
+// - the model is a CTMC if some event or mode has a rate, every command then
+//   having its rate (1 by default) and probabilities being ignored;
+// - otherwise the model is an MDP where the actions are chosen
+//   nondeterministically, except for the success and failure modes with a
+//   probability: they are the outcomes of a single command, the skill running
+//   on with the remaining probability. The command is enabled where the
+//   invariants of the skill and the postconditions of all these modes hold,
+//   before the effects, so that no outcome fires where its own guard does
+//   not hold;
+// - an event with a probability occurs with this probability when chosen;
+// - each state of a resource has a label R_S for the queries, and each
+//   constraint of the skillset a label named after it.
+
+//------------------------- Naming -------------------------
+
+fn state_name(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!("{}_{}", resource.name(), skillset.get(id).unwrap().name())
+}
+
+fn running_name(skill: &Skill) -> String {
+    format!("{}_running", skill.name())
+}
+
+fn number(x: f64) -> String {
+    let s = format!("{:.10}", x);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    s.to_string()
+}
+
+//------------------------- Expr -------------------------
+
+pub fn prism_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "true".into(),
+        Expr::False => "false".into(),
+        Expr::ResourceEq(resource, state) => format!(
+            "{}={}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::ResourceNe(resource, state) => format!(
+            "{}!={}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::Not(e) => format!("!({})", prism_expr(skillset, e)),
+        Expr::And(l, r) => format!(
+            "({} & {})",
+            prism_expr(skillset, l),
+            prism_expr(skillset, r)
+        ),
+        Expr::Or(l, r) => format!(
+            "({} | {})",
+            prism_expr(skillset, l),
+            prism_expr(skillset, r)
+        ),
+        Expr::Implies(l, r) => format!(
+            "({} => {})",
+            prism_expr(skillset, l),
+            prism_expr(skillset, r)
+        ),
+    }
+}
+
+//------------------------- Action -------------------------
+
+fn likelihood(skillset: &Skillset, action: &Action) -> Option<Likelihood> {
+    match action {
+        Action::Event(id) => skillset.get(*id).unwrap().likelihood(),
+        Action::Success(id) => skillset.get(*id).unwrap().likelihood(),
+        Action::Failure(id) => skillset.get(*id).unwrap().likelihood(),
+        _ => None,
+    }
+}
+
+fn is_ctmc(skillset: &Skillset) -> bool {
+    skillset
+        .actions()
+        .iter()
+        .any(|x| matches!(likelihood(skillset, x), Some(Likelihood::Rate(_))))
+}
+
+fn guard(skillset: &Skillset, action: &Action, guard: &Expr) -> String {
+    let guard = prism_expr(skillset, guard);
+    match action.skill() {
+        None => guard,
+        Some(id) => {
+            let running = running_name(skillset.get(id).unwrap());
+            match action {
+                Action::Start(_) => format!("!{} & {}", running, guard),
+                _ => format!("{} & {}", running, guard),
+            }
+        }
+    }
+}
+
+fn update(skillset: &Skillset, action: &Action) -> String {
+    let mut v = Vec::new();
+    for effect in action.effects(skillset) {
+        v.push(format!(
+            "({}'={})",
+            effect.resource().to_lang(skillset),
+            state_name(skillset, effect.state().resolved())
+        ));
+    }
+    if let Some(id) = action.skill() {
+        v.push(format!(
+            "({}'={})",
+            running_name(skillset.get(id).unwrap()),
+            action.running_after().unwrap()
+        ));
+    }
+    if v.is_empty() {
+        "true".into()
+    } else {
+        v.join(" & ")
+    }
+}
+
+fn command(label: &str, guard: &str, outcomes: &[(Option<f64>, String)]) -> String {
+    let v: Vec<String> = outcomes
+        .iter()
+        .map(|(x, update)| match x {
+            Some(x) => format!("{} : {}", number(*x), update),
+            None => update.clone(),
+        })
+        .collect();
+    format!("\t[{}] {} -> {};\n", label, guard, v.join(" + "))
+}
+
+fn ctmc_commands(skillset: &Skillset) -> String {
+    let mut s = String::new();
+    for action in skillset.actions() {
+        let rate = match likelihood(skillset, &action) {
+            Some(Likelihood::Rate(x)) => x,
+            _ => 1.0,
+        };
+        s.push_str(&command(
+            &action.name(skillset),
+            &guard(skillset, &action, &action.guard(skillset)),
+            &[(Some(rate), update(skillset, &action))],
+        ));
+    }
+    s
+}
+
+fn mdp_commands(skillset: &Skillset) -> String {
+    let mut s = String::new();
+    for action in skillset.actions() {
+        let name = action.name(skillset);
+        match (action, likelihood(skillset, &action)) {
+            (Action::Success(_), Some(_)) | (Action::Failure(_), Some(_)) => {}
+            (Action::Event(_), Some(Likelihood::Probability(p))) => {
+                let outcomes = [
+                    (Some(p), update(skillset, &action)),
+                    (Some(1.0 - p), "true".into()),
+                ];
+                let guard = guard(skillset, &action, &action.guard(skillset));
+                s.push_str(&command(&name, &guard, &outcomes));
+            }
+            _ => {
+                let guard = guard(skillset, &action, &action.guard(skillset));
+                s.push_str(&command(
+                    &name,
+                    &guard,
+                    &[(None, update(skillset, &action))],
+                ));
+            }
+        }
+    }
+    // Outcomes of the skills
+    for skill in skillset.skills() {
+        let mut outcomes = Vec::new();
+        let mut postconditions = Vec::new();
+        let mut remaining = 1.0;
+        for action in skillset.actions() {
+            if action.skill() != Some(skill.id()) {
+                continue;
+            }
+            if let Action::Success(_) | Action::Failure(_) = action {
+                if let Some(Likelihood::Probability(p)) = likelihood(skillset, &action) {
+                    outcomes.push((Some(p), update(skillset, &action)));
+                    postconditions.push(action.postconditions(skillset));
+                    remaining -= p;
+                }
+            }
+        }
+        if outcomes.is_empty() {
+            continue;
+        }
+        if remaining > 1e-9 {
+            outcomes.push((Some(remaining), "true".into()));
+        }
+        let condition = Expr::and_all(
+            skill
+                .invariants()
+                .iter()
+                .map(|x| x.guard().clone())
+                .chain(postconditions),
+        )
+        .simplified();
+        let guard = guard(skillset, &Action::Interrupt(skill.id()), &condition);
+        s.push_str(&command(
+            &format!("{}_outcome", skill.name()),
+            &guard,
+            &outcomes,
+        ));
+    }
+    s
+}
+
+//------------------------- Skillset -------------------------
+
+/// Translate a resolved skillset into a PRISM model: a CTMC if it has rates,
+/// an MDP otherwise.
+pub fn to_prism(skillset: &Skillset) -> String {
+    let ctmc = is_ctmc(skillset);
+    let mut s = format!(
+        "// skillset {}\n\n{}\n\n",
+        skillset.name(),
+        if ctmc { "ctmc" } else { "mdp" }
+    );
+    // States
+    for resource in skillset.resources() {
+        for (i, state) in resource.states().iter().enumerate() {
+            s.push_str(&format!(
+                "const int {} = {};\n",
+                state_name(skillset, state.id()),
+                i
+            ));
+        }
+    }
+    // Variables
+    s.push_str(&format!("\nmodule {}\n", skillset.name()));
+    for resource in skillset.resources() {
+        s.push_str(&format!(
+            "\t{} : [0..{}] init {};\n",
+            resource.name(),
+            resource.states().len().max(1) - 1,
+            state_name(skillset, resource.initial())
+        ));
+    }
+    for skill in skillset.skills() {
+        s.push_str(&format!("\t{} : bool init false;\n", running_name(skill)));
+    }
+    s.push('\n');
+    // Commands
+    if ctmc {
+        s.push_str(&ctmc_commands(skillset));
+    } else {
+        s.push_str(&mdp_commands(skillset));
+    }
+    s.push_str("endmodule\n");
+    // Labels
+    if !skillset.resources().is_empty() || !skillset.constraints().is_empty() {
+        s.push('\n');
+    }
+    for resource in skillset.resources() {
+        for state in resource.states() {
+            let name = state_name(skillset, state.id());
+            s.push_str(&format!(
+                "label \"{}\" = {}={};\n",
+                name,
+                resource.name(),
+                name
+            ));
+        }
+    }
+    for constraint in skillset.constraints() {
+        s.push_str(&format!(
+            "label \"{}\" = {};\n",
+            constraint.name(),
+            prism_expr(skillset, constraint.expr())
+        ));
+    }
+    s
+}
@@ -0,0 +1,188 @@
+use crate::model::*;
+
+// Translation:
+// - the states of all the resources are the mtype constants R_S, and each
+//   resource R is an mtype variable, each skill S has a bool S_running;
+// - each event is a proctype looping on its guarded effects, each skill is a
+//   proctype looping on the steps of its lifecycle, every step being atomic;
+// - the invariants of a skill are asserted after its start;
+// - each constraint of the skillset and the property are ltl formulas that
+//   must always hold.
+
+//------------------------- Naming -------------------------
+
+fn state_name(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!("{}_{}", resource.name(), skillset.get(id).unwrap().name())
+}
+
+fn running_name(skill: &Skill) -> String {
+    format!("{}_running", skill.name())
+}
+
+//------------------------- Expr -------------------------
+
+pub fn promela_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "true".into(),
+        Expr::False => "false".into(),
+        Expr::ResourceEq(resource, state) => format!(
+            "{} == {}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::ResourceNe(resource, state) => format!(
+            "{} != {}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::Not(e) => format!("!({})", promela_expr(skillset, e)),
+        Expr::And(l, r) => format!(
+            "({} && {})",
+            promela_expr(skillset, l),
+            promela_expr(skillset, r)
+        ),
+        Expr::Or(l, r) => format!(
+            "({} || {})",
+            promela_expr(skillset, l),
+            promela_expr(skillset, r)
+        ),
+        Expr::Implies(l, r) => format!(
+            "(!({}) || {})",
+            promela_expr(skillset, l),
+            promela_expr(skillset, r)
+        ),
+    }
+}
+
+//------------------------- Action -------------------------
+
+/// An option of a do loop performing the action atomically.
+fn option(skillset: &Skillset, action: &Action) -> String {
+    let mut guard = promela_expr(skillset, &action.guard(skillset));
+    if let Some(id) = action.skill() {
+        let running = running_name(skillset.get(id).unwrap());
+        guard = match action {
+            Action::Start(_) => format!("!{} && {}", running, guard),
+            _ => format!("{} && {}", running, guard),
+        };
+    }
+    let mut statements = Vec::new();
+    for effect in action.effects(skillset) {
+        statements.push(format!(
+            "{} = {}",
+            effect.resource().to_lang(skillset),
+            state_name(skillset, effect.state().resolved())
+        ));
+    }
+    if let Some(id) = action.skill() {
+        let skill = skillset.get(id).unwrap();
+        statements.push(format!(
+            "{} = {}",
+            running_name(skill),
+            action.running_after().unwrap()
+        ));
+        if let Action::Start(_) = action {
+            for invariant in skill.invariants() {
+                statements.push(format!(
+                    "assert({})",
+                    promela_expr(skillset, invariant.guard())
+                ));
+            }
+        }
+    }
+    if statements.is_empty() {
+        statements.push("skip".into());
+    }
+    let mut s = format!(
+        "\t:: atomic {{ /* {} */\n\t\t({}) ->\n",
+        action.to_lang(skillset),
+        guard
+    );
+    for statement in statements {
+        s.push_str(&format!("\t\t{};\n", statement));
+    }
+    s.push_str("\t}\n");
+    s
+}
+
+fn proctype(skillset: &Skillset, name: &str, actions: &[Action]) -> String {
+    let mut s = format!("proctype {}() {{\n\tdo\n", name);
+    for action in actions {
+        s.push_str(&option(skillset, action));
+    }
+    s.push_str("\tod\n}\n");
+    s
+}
+
+//------------------------- Skillset -------------------------
+
+/// Translate a resolved skillset into a Promela model for SPIN, with an
+/// optional property that must hold in every reachable state.
+pub fn to_promela(skillset: &Skillset, property: Option<&Expr>) -> String {
+    let actions = skillset.actions();
+    let mut s = format!("/* skillset {} */\n\n", skillset.name());
+    // States
+    let mut states = Vec::new();
+    for resource in skillset.resources() {
+        for state in resource.states() {
+            states.push(state_name(skillset, state.id()));
+        }
+    }
+    if !states.is_empty() {
+        s.push_str(&format!("mtype = {{ {} }};\n\n", states.join(", ")));
+    }
+    // Variables
+    for resource in skillset.resources() {
+        s.push_str(&format!(
+            "mtype {} = {};\n",
+            resource.name(),
+            state_name(skillset, resource.initial())
+        ));
+    }
+    for skill in skillset.skills() {
+        s.push_str(&format!("bool {} = false;\n", running_name(skill)));
+    }
+    // Events
+    let mut processes = Vec::new();
+    for event in skillset.events() {
+        let name = format!("event_{}", event.name());
+        s.push('\n');
+        s.push_str(&proctype(skillset, &name, &[Action::Event(event.id())]));
+        processes.push(name);
+    }
+    // Skills
+    for skill in skillset.skills() {
+        let lifecycle: Vec<Action> = actions
+            .iter()
+            .filter(|x| x.skill() == Some(skill.id()))
+            .cloned()
+            .collect();
+        let name = format!("skill_{}", skill.name());
+        s.push('\n');
+        s.push_str(&proctype(skillset, &name, &lifecycle));
+        processes.push(name);
+    }
+    // Init
+    s.push_str("\ninit {\n\tatomic {\n");
+    for name in processes {
+        s.push_str(&format!("\t\trun {}();\n", name));
+    }
+    s.push_str("\t}\n}\n");
+    // Constraints
+    for constraint in skillset.constraints() {
+        s.push_str(&format!(
+            "\nltl {} {{ [] ({}) }}\n",
+            constraint.name(),
+            promela_expr(skillset, constraint.expr())
+        ));
+    }
+    // Property
+    if let Some(property) = property {
+        s.push_str(&format!(
+            "\nltl property {{ [] ({}) }}\n",
+            promela_expr(skillset, property)
+        ));
+    }
+    s
+}
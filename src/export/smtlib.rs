@@ -0,0 +1,286 @@
+use crate::model::*;
+
+// Translation:
+// - each resource R is a datatype R_t whose constructors are its states R_S,
+//   and each skill S has a boolean S_running;
+// - the actions are the constructors of the Action datatype, plus stutter;
+// - init and trans are functions over the state (and the next state R.next,
+//   S_running.next for trans); like the other backends, trans lets effects
+//   perform undeclared transitions, that the analyses report;
+// - the unrolling declares the state R.i, S_running.i for each step i and the
+//   action action.i taken at step i, then asserts init and trans;
+// - the property, conjoined with the constraints of the skillset, is asserted
+//   to be violated at some step, so that a model is a counterexample;
+// - the script ends with check-sat only, as get-model is an error after unsat:
+//   unsat means that the property holds for these steps, and on sat the
+//   counterexample is printed by the solver (z3 -model, cvc5 --dump-models).
+
+//------------------------- Naming -------------------------
+
+fn state_name(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!("{}_{}", resource.name(), skillset.get(id).unwrap().name())
+}
+
+fn type_name(resource: &Resource) -> String {
+    format!("{}_t", resource.name())
+}
+
+fn running_name(skill: &Skill) -> String {
+    format!("{}_running", skill.name())
+}
+
+/// The state variables with a suffix ("" for the current state).
+fn variables(skillset: &Skillset, suffix: &str) -> Vec<(String, String)> {
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        v.push((
+            format!("{}{}", resource.name(), suffix),
+            type_name(resource),
+        ));
+    }
+    for skill in skillset.skills() {
+        v.push((format!("{}{}", running_name(skill), suffix), "Bool".into()));
+    }
+    v
+}
+
+fn parameters(variables: &[(String, String)]) -> String {
+    let v: Vec<String> = variables
+        .iter()
+        .map(|(name, t)| format!("({} {})", name, t))
+        .collect();
+    v.join(" ")
+}
+
+fn arguments(variables: &[(String, String)]) -> String {
+    let v: Vec<String> = variables.iter().map(|(name, _)| name.clone()).collect();
+    v.join(" ")
+}
+
+fn and(v: Vec<String>) -> String {
+    match v.len() {
+        0 => "true".into(),
+        1 => v[0].clone(),
+        _ => format!("(and {})", v.join(" ")),
+    }
+}
+
+fn or(v: Vec<String>) -> String {
+    match v.len() {
+        0 => "false".into(),
+        1 => v[0].clone(),
+        _ => format!("(or {})", v.join(" ")),
+    }
+}
+
+//------------------------- Expr -------------------------
+
+/// An expression over the current state variables.
+pub fn smtlib_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "true".into(),
+        Expr::False => "false".into(),
+        Expr::ResourceEq(resource, state) => format!(
+            "(= {} {})",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::ResourceNe(resource, state) => format!(
+            "(not (= {} {}))",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::Not(e) => format!("(not {})", smtlib_expr(skillset, e)),
+        Expr::And(l, r) => format!(
+            "(and {} {})",
+            smtlib_expr(skillset, l),
+            smtlib_expr(skillset, r)
+        ),
+        Expr::Or(l, r) => format!(
+            "(or {} {})",
+            smtlib_expr(skillset, l),
+            smtlib_expr(skillset, r)
+        ),
+        Expr::Implies(l, r) => format!(
+            "(=> {} {})",
+            smtlib_expr(skillset, l),
+            smtlib_expr(skillset, r)
+        ),
+    }
+}
+
+//------------------------- Declarations -------------------------
+
+fn declarations(skillset: &Skillset) -> String {
+    let mut s = String::from("; Resources\n");
+    for resource in skillset.resources() {
+        let states: Vec<String> = resource
+            .states()
+            .iter()
+            .map(|x| format!("({})", state_name(skillset, x.id())))
+            .collect();
+        s.push_str(&format!(
+            "(declare-datatypes (({} 0)) (({})))\n",
+            type_name(resource),
+            states.join(" ")
+        ));
+    }
+    s.push_str("; Actions\n");
+    let mut actions = vec!["(stutter)".to_string()];
+    actions.extend(
+        skillset
+            .actions()
+            .iter()
+            .map(|x| format!("({})", x.name(skillset))),
+    );
+    s.push_str(&format!(
+        "(declare-datatypes ((Action 0)) (({})))\n",
+        actions.join(" ")
+    ));
+    s
+}
+
+fn init(skillset: &Skillset) -> String {
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        v.push(format!(
+            "(= {} {})",
+            resource.name(),
+            state_name(skillset, resource.initial())
+        ));
+    }
+    for skill in skillset.skills() {
+        v.push(format!("(not {})", running_name(skill)));
+    }
+    format!(
+        "; Initial state\n(define-fun init ({}) Bool\n\t{})\n",
+        parameters(&variables(skillset, "")),
+        and(v)
+    )
+}
+
+/// The next state of the variables when the action occurs.
+fn next_state(skillset: &Skillset, action: Option<&Action>) -> Vec<String> {
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        let target = action.and_then(|a| {
+            a.effects(skillset)
+                .iter()
+                .rev()
+                .find(|x| x.resource().resolved() == resource.id())
+        });
+        let value = match target {
+            Some(effect) => state_name(skillset, effect.state().resolved()),
+            None => resource.name().to_string(),
+        };
+        v.push(format!("(= {}.next {})", resource.name(), value));
+    }
+    for skill in skillset.skills() {
+        let value = match action {
+            Some(a) if a.skill() == Some(skill.id()) => a.running_after().unwrap().to_string(),
+            _ => running_name(skill),
+        };
+        v.push(format!("(= {}.next {})", running_name(skill), value));
+    }
+    v
+}
+
+fn trans(skillset: &Skillset) -> String {
+    let mut v = vec![format!(
+        "(=> (= action stutter) {})",
+        and(next_state(skillset, None))
+    )];
+    for action in skillset.actions() {
+        let mut conditions = Vec::new();
+        if let Some(id) = action.skill() {
+            let running = running_name(skillset.get(id).unwrap());
+            match action {
+                Action::Start(_) => conditions.push(format!("(not {})", running)),
+                _ => conditions.push(running),
+            }
+        }
+        conditions.push(smtlib_expr(skillset, &action.guard(skillset)));
+        conditions.extend(next_state(skillset, Some(&action)));
+        v.push(format!(
+            "(=> (= action {}) {})",
+            action.name(skillset),
+            and(conditions)
+        ));
+    }
+    format!(
+        "; Transition relation\n(define-fun trans ({} (action Action) {}) Bool\n\t(and\n\t\t{}))\n",
+        parameters(&variables(skillset, "")),
+        parameters(&variables(skillset, ".next")),
+        v.join("\n\t\t")
+    )
+}
+
+//------------------------- Skillset -------------------------
+
+/// Translate a resolved skillset into SMT-LIB 2, unrolled for the given number
+/// of steps. With a property or constraints, the output is satisfiable if and
+/// only if one of them can be violated within these steps: unsat means that
+/// they hold, and a model of a sat answer is a counterexample.
+pub fn to_smtlib(skillset: &Skillset, steps: usize, property: Option<&Expr>) -> String {
+    let mut checked: Vec<Expr> = skillset
+        .constraints()
+        .iter()
+        .map(|x| x.expr().clone())
+        .collect();
+    checked.extend(property.cloned());
+    let property = if checked.is_empty() {
+        None
+    } else {
+        Some(Expr::and_all(checked))
+    };
+    let property = property.as_ref();
+    let mut s = format!("; skillset {}\n(set-logic ALL)\n", skillset.name());
+    s.push_str(&declarations(skillset));
+    s.push_str(&init(skillset));
+    s.push_str(&trans(skillset));
+    if let Some(property) = property {
+        s.push_str(&format!(
+            "; Property\n(define-fun property ({}) Bool\n\t{})\n",
+            parameters(&variables(skillset, "")),
+            smtlib_expr(skillset, property)
+        ));
+    }
+    // Unrolling
+    s.push_str(&format!("; Unrolling for {} steps\n", steps));
+    for i in 0..=steps {
+        for (name, t) in variables(skillset, &format!(".{}", i)) {
+            s.push_str(&format!("(declare-const {} {})\n", name, t));
+        }
+        if i == 0 {
+            s.push_str(&format!(
+                "(assert (init {}))\n",
+                arguments(&variables(skillset, ".0"))
+            ));
+        } else {
+            s.push_str(&format!("(declare-const action.{} Action)\n", i - 1));
+            s.push_str(&format!(
+                "(assert (trans {} action.{} {}))\n",
+                arguments(&variables(skillset, &format!(".{}", i - 1))),
+                i - 1,
+                arguments(&variables(skillset, &format!(".{}", i)))
+            ));
+        }
+    }
+    if property.is_some() {
+        let v: Vec<String> = (0..=steps)
+            .map(|i| {
+                format!(
+                    "(not (property {}))",
+                    arguments(&variables(skillset, &format!(".{}", i)))
+                )
+            })
+            .collect();
+        s.push_str(&format!(
+            "; Violation of the property\n(assert {})\n",
+            or(v)
+        ));
+    }
+    s.push_str("(check-sat)\n");
+    s
+}
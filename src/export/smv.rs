@@ -0,0 +1,160 @@
+use crate::model::*;
+
+//------------------------- Naming -------------------------
+
+fn state_name(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!("{}_{}", resource.name(), skillset.get(id).unwrap().name())
+}
+
+fn status_name(skill: &Skill) -> String {
+    format!("{}_status", skill.name())
+}
+
+//------------------------- Expr -------------------------
+
+pub fn smv_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "TRUE".into(),
+        Expr::False => "FALSE".into(),
+        Expr::ResourceEq(resource, state) => format!(
+            "{} = {}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::ResourceNe(resource, state) => format!(
+            "{} != {}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::Not(e) => format!("!({})", smv_expr(skillset, e)),
+        Expr::And(l, r) => format!("({} & {})", smv_expr(skillset, l), smv_expr(skillset, r)),
+        Expr::Or(l, r) => format!("({} | {})", smv_expr(skillset, l), smv_expr(skillset, r)),
+        Expr::Implies(l, r) => format!("({} -> {})", smv_expr(skillset, l), smv_expr(skillset, r)),
+    }
+}
+
+//------------------------- Skillset -------------------------
+
+/// Translate a resolved skillset into a NuSMV/nuXmv main module.
+///
+/// At each step the input variable `action` selects one enabled action
+/// (or `stutter`), whose effects give the next state of the resources.
+/// The constraints of the skillset are invariant specifications.
+pub fn to_smv(skillset: &Skillset) -> String {
+    let actions = skillset.actions();
+    let mut s = format!("-- skillset {}\n\nMODULE main\n", skillset.name());
+    // Variables
+    s.push_str("\nVAR\n");
+    for resource in skillset.resources() {
+        let states: Vec<String> = resource
+            .states()
+            .iter()
+            .map(|x| state_name(skillset, x.id()))
+            .collect();
+        s.push_str(&format!(
+            "\t{} : {{{}}};\n",
+            resource.name(),
+            states.join(", ")
+        ));
+    }
+    for skill in skillset.skills() {
+        s.push_str(&format!("\t{} : {{idle, running}};\n", status_name(skill)));
+    }
+    // Action
+    s.push_str("\nIVAR\n");
+    let mut names = vec!["stutter".to_string()];
+    names.extend(actions.iter().map(|x| x.name(skillset)));
+    s.push_str(&format!("\taction : {{{}}};\n", names.join(", ")));
+    // Enabled
+    if !actions.is_empty() {
+        s.push_str("\nDEFINE\n");
+        for action in actions.iter() {
+            let guard = smv_expr(skillset, &action.guard(skillset));
+            let enabled = match action.skill() {
+                None => guard,
+                Some(id) => {
+                    let skill = skillset.get(id).unwrap();
+                    let status = match action {
+                        Action::Start(_) => "idle",
+                        _ => "running",
+                    };
+                    format!("{} = {} & {}", status_name(skill), status, guard)
+                }
+            };
+            s.push_str(&format!(
+                "\t{}_enabled := {};\n",
+                action.name(skillset),
+                enabled
+            ));
+        }
+        s.push_str("\nTRANS\n");
+        let trans: Vec<String> = actions
+            .iter()
+            .map(|x| {
+                let name = x.name(skillset);
+                format!("\t(action = {} -> {}_enabled)", name, name)
+            })
+            .collect();
+        s.push_str(&trans.join(" &\n"));
+        s.push_str(";\n");
+    }
+    // Init
+    s.push_str("\nASSIGN\n");
+    for resource in skillset.resources() {
+        s.push_str(&format!(
+            "\tinit({}) := {};\n",
+            resource.name(),
+            state_name(skillset, resource.initial())
+        ));
+    }
+    for skill in skillset.skills() {
+        s.push_str(&format!("\tinit({}) := idle;\n", status_name(skill)));
+    }
+    // Next resources
+    for resource in skillset.resources() {
+        s.push_str(&format!("\tnext({}) := case\n", resource.name()));
+        for action in actions.iter() {
+            for effect in action.effects(skillset) {
+                if effect.resource().resolved() == resource.id() {
+                    s.push_str(&format!(
+                        "\t\taction = {} : {};\n",
+                        action.name(skillset),
+                        state_name(skillset, effect.state().resolved())
+                    ));
+                }
+            }
+        }
+        s.push_str(&format!("\t\tTRUE : {};\n\tesac;\n", resource.name()));
+    }
+    // Next skills
+    for skill in skillset.skills() {
+        s.push_str(&format!("\tnext({}) := case\n", status_name(skill)));
+        for action in actions.iter() {
+            if action.skill() == Some(skill.id()) {
+                let status = match action.running_after() {
+                    Some(true) => "running",
+                    _ => "idle",
+                };
+                s.push_str(&format!(
+                    "\t\taction = {} : {};\n",
+                    action.name(skillset),
+                    status
+                ));
+            }
+        }
+        s.push_str(&format!("\t\tTRUE : {};\n\tesac;\n", status_name(skill)));
+    }
+    // Constraints
+    if !skillset.constraints().is_empty() {
+        s.push('\n');
+    }
+    for constraint in skillset.constraints() {
+        s.push_str(&format!(
+            "INVARSPEC NAME {} := {};\n",
+            constraint.name(),
+            smv_expr(skillset, constraint.expr())
+        ));
+    }
+    s
+}
@@ -0,0 +1,240 @@
+use crate::model::*;
+
+// Translation:
+// - each resource R is a variable whose values are the names of its states as
+//   strings, each skill S has a variable S_status ("idle" or "running");
+// - each action is a TLA+ action named after Action::name, Next being their
+//   disjunction;
+// - an invariant may be violated by an event while its skill is running,
+//   stopping the skill, so it is not a state predicate: it is checked as an
+//   action property, holding whenever the skill starts;
+// - TLC checks TypeOK, the constraints of the skillset and the property as
+//   invariants.
+
+//------------------------- Naming -------------------------
+
+fn state_value(skillset: &Skillset, id: StateId) -> String {
+    format!("\"{}\"", skillset.get(id).unwrap().name())
+}
+
+fn status_name(skill: &Skill) -> String {
+    format!("{}_status", skill.name())
+}
+
+fn invariant_name(skillset: &Skillset, id: InvariantId) -> String {
+    format!(
+        "{}_{}_at_start",
+        skillset.get(id.skill()).unwrap().name(),
+        skillset.get(id).unwrap().name()
+    )
+}
+
+fn constraint_name(constraint: &Constraint) -> String {
+    format!("{}_holds", constraint.name())
+}
+
+fn variables(skillset: &Skillset) -> Vec<String> {
+    let mut v: Vec<String> = skillset
+        .resources()
+        .iter()
+        .map(|x| x.name().to_string())
+        .collect();
+    v.extend(skillset.skills().iter().map(status_name));
+    v
+}
+
+fn conjunction(v: Vec<String>) -> String {
+    match v.len() {
+        0 => "TRUE".into(),
+        _ => v
+            .iter()
+            .map(|x| format!("\n    /\\ {}", x))
+            .collect::<Vec<_>>()
+            .join(""),
+    }
+}
+
+fn disjunction(v: Vec<String>) -> String {
+    match v.len() {
+        0 => "FALSE".into(),
+        _ => v
+            .iter()
+            .map(|x| format!("\n    \\/ {}", x))
+            .collect::<Vec<_>>()
+            .join(""),
+    }
+}
+
+//------------------------- Expr -------------------------
+
+pub fn tla_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "TRUE".into(),
+        Expr::False => "FALSE".into(),
+        Expr::ResourceEq(resource, state) => format!(
+            "{} = {}",
+            resource.to_lang(skillset),
+            state_value(skillset, state.resolved())
+        ),
+        Expr::ResourceNe(resource, state) => format!(
+            "{} # {}",
+            resource.to_lang(skillset),
+            state_value(skillset, state.resolved())
+        ),
+        Expr::Not(e) => format!("~({})", tla_expr(skillset, e)),
+        Expr::And(l, r) => format!("({} /\\ {})", tla_expr(skillset, l), tla_expr(skillset, r)),
+        Expr::Or(l, r) => format!("({} \\/ {})", tla_expr(skillset, l), tla_expr(skillset, r)),
+        Expr::Implies(l, r) => format!("({} => {})", tla_expr(skillset, l), tla_expr(skillset, r)),
+    }
+}
+
+//------------------------- Action -------------------------
+
+fn action(skillset: &Skillset, action: &Action) -> String {
+    let mut v = Vec::new();
+    let mut unchanged = Vec::new();
+    if let Some(id) = action.skill() {
+        let skill = skillset.get(id).unwrap();
+        let status = match action {
+            Action::Start(_) => "idle",
+            _ => "running",
+        };
+        v.push(format!("{} = \"{}\"", status_name(skill), status));
+    }
+    v.push(tla_expr(skillset, &action.guard(skillset)));
+    for resource in skillset.resources() {
+        let target = action
+            .effects(skillset)
+            .iter()
+            .rev()
+            .find(|x| x.resource().resolved() == resource.id());
+        match target {
+            Some(effect) => v.push(format!(
+                "{}' = {}",
+                resource.name(),
+                state_value(skillset, effect.state().resolved())
+            )),
+            None => unchanged.push(resource.name().to_string()),
+        }
+    }
+    for skill in skillset.skills() {
+        match action.running_after() {
+            Some(running) if action.skill() == Some(skill.id()) => {
+                let status = if running { "running" } else { "idle" };
+                v.push(format!("{}' = \"{}\"", status_name(skill), status));
+            }
+            _ => unchanged.push(status_name(skill)),
+        }
+    }
+    if !unchanged.is_empty() {
+        v.push(format!("UNCHANGED <<{}>>", unchanged.join(", ")));
+    }
+    format!(
+        "\\* {}\n{} =={}\n",
+        action.to_lang(skillset),
+        action.name(skillset),
+        conjunction(v)
+    )
+}
+
+//------------------------- Skillset -------------------------
+
+/// Translate a resolved skillset into a TLA+ module named after the skillset,
+/// with an optional property that must hold in every reachable state.
+pub fn to_tla(skillset: &Skillset, property: Option<&Expr>) -> String {
+    let actions = skillset.actions();
+    let variables = variables(skillset);
+    let mut s = format!("---- MODULE {} ----\n\n", skillset.name());
+    if !variables.is_empty() {
+        s.push_str(&format!("VARIABLES {}\n\n", variables.join(", ")));
+    }
+    s.push_str(&format!("vars == <<{}>>\n\n", variables.join(", ")));
+    // Type
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        let states: Vec<String> = resource
+            .states()
+            .iter()
+            .map(|x| state_value(skillset, x.id()))
+            .collect();
+        v.push(format!(
+            "{} \\in {{{}}}",
+            resource.name(),
+            states.join(", ")
+        ));
+    }
+    for skill in skillset.skills() {
+        v.push(format!(
+            "{} \\in {{\"idle\", \"running\"}}",
+            status_name(skill)
+        ));
+    }
+    s.push_str(&format!("TypeOK =={}\n\n", conjunction(v)));
+    // Init
+    let mut v = Vec::new();
+    for resource in skillset.resources() {
+        v.push(format!(
+            "{} = {}",
+            resource.name(),
+            state_value(skillset, resource.initial())
+        ));
+    }
+    for skill in skillset.skills() {
+        v.push(format!("{} = \"idle\"", status_name(skill)));
+    }
+    s.push_str(&format!("Init =={}\n\n", conjunction(v)));
+    // Actions
+    for a in actions.iter() {
+        s.push_str(&action(skillset, a));
+        s.push('\n');
+    }
+    // Next
+    let v = actions.iter().map(|x| x.name(skillset)).collect();
+    s.push_str(&format!("Next =={}\n\n", disjunction(v)));
+    s.push_str("Spec == Init /\\ [][Next]_vars\n\n");
+    // Invariants
+    for skill in skillset.skills() {
+        for invariant in skill.invariants() {
+            s.push_str(&format!(
+                "{} == [][{} = \"idle\" /\\ {}' = \"running\" => ({})']_vars\n\n",
+                invariant_name(skillset, invariant.id()),
+                status_name(skill),
+                status_name(skill),
+                tla_expr(skillset, invariant.guard())
+            ));
+        }
+    }
+    for constraint in skillset.constraints() {
+        s.push_str(&format!(
+            "{} == {}\n\n",
+            constraint_name(constraint),
+            tla_expr(skillset, constraint.expr())
+        ));
+    }
+    if let Some(property) = property {
+        s.push_str(&format!("Property == {}\n\n", tla_expr(skillset, property)));
+    }
+    s.push_str("====\n");
+    s
+}
+
+/// The TLC configuration checking the invariants and the properties of the
+/// module given by to_tla.
+pub fn to_tla_cfg(skillset: &Skillset, property: bool) -> String {
+    let mut s = String::from("SPECIFICATION Spec\n\nINVARIANT TypeOK\n");
+    for constraint in skillset.constraints() {
+        s.push_str(&format!("INVARIANT {}\n", constraint_name(constraint)));
+    }
+    if property {
+        s.push_str("INVARIANT Property\n");
+    }
+    for skill in skillset.skills() {
+        for invariant in skill.invariants() {
+            s.push_str(&format!(
+                "PROPERTY {}\n",
+                invariant_name(skillset, invariant.id())
+            ));
+        }
+    }
+    s
+}
@@ -0,0 +1,394 @@
+use crate::model::*;
+
+// Translation:
+// - each resource R is a global int R (with constants R_State) and a template
+//   R_resource whose locations are the states and whose edges are the
+//   declared transitions, synchronised on the channels R_to_State;
+// - the events are the edges of a single template events;
+// - each skill S is a template S_skill with locations idle and running;
+// - effects are sent on the R_to_State channels, in sequence through committed
+//   locations when there are several (an effect that is not a declared
+//   transition blocks, and shows up as a deadlock);
+// - a progress period becomes the clock S_clock of the skill template, and
+//   the progress is published on the broadcast channel S_progress;
+// - the queries are the absence of deadlock and the constraints; the
+//   invariants of the skills are not queried, as an event may violate them
+//   while the skill is running, stopping it.
+
+//------------------------- Naming -------------------------
+
+fn state_name(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!("{}_{}", resource.name(), skillset.get(id).unwrap().name())
+}
+
+fn channel_name(skillset: &Skillset, id: StateId) -> String {
+    let resource = skillset.get(id.resource()).unwrap();
+    format!(
+        "{}_to_{}",
+        resource.name(),
+        skillset.get(id).unwrap().name()
+    )
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+//------------------------- Expr -------------------------
+
+pub fn uppaal_expr(skillset: &Skillset, expr: &Expr) -> String {
+    match expr {
+        Expr::True => "true".into(),
+        Expr::False => "false".into(),
+        Expr::ResourceEq(resource, state) => format!(
+            "{} == {}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::ResourceNe(resource, state) => format!(
+            "{} != {}",
+            resource.to_lang(skillset),
+            state_name(skillset, state.resolved())
+        ),
+        Expr::Not(e) => format!("!({})", uppaal_expr(skillset, e)),
+        Expr::And(l, r) => format!(
+            "({} && {})",
+            uppaal_expr(skillset, l),
+            uppaal_expr(skillset, r)
+        ),
+        Expr::Or(l, r) => format!(
+            "({} || {})",
+            uppaal_expr(skillset, l),
+            uppaal_expr(skillset, r)
+        ),
+        Expr::Implies(l, r) => format!(
+            "({} imply {})",
+            uppaal_expr(skillset, l),
+            uppaal_expr(skillset, r)
+        ),
+    }
+}
+
+//------------------------- Template -------------------------
+
+struct Location {
+    id: usize,
+    name: Option<String>,
+    invariant: Option<String>,
+    committed: bool,
+}
+
+struct Edge {
+    source: usize,
+    target: usize,
+    guard: Option<String>,
+    synchronisation: Option<String>,
+    assignment: Option<String>,
+}
+
+struct Template {
+    name: String,
+    declaration: String,
+    locations: Vec<Location>,
+    init: usize,
+    edges: Vec<Edge>,
+}
+
+impl Template {
+    fn new<S: Into<String>>(name: S) -> Self {
+        Self {
+            name: name.into(),
+            declaration: String::new(),
+            locations: Vec::new(),
+            init: 0,
+            edges: Vec::new(),
+        }
+    }
+
+    fn add_location(&mut self, ids: &mut usize, name: Option<String>, committed: bool) -> usize {
+        let id = *ids;
+        *ids += 1;
+        self.locations.push(Location {
+            id,
+            name,
+            invariant: None,
+            committed,
+        });
+        id
+    }
+
+    fn add_edge(
+        &mut self,
+        source: usize,
+        target: usize,
+        guard: Option<String>,
+        synchronisation: Option<String>,
+        assignment: Option<String>,
+    ) {
+        self.edges.push(Edge {
+            source,
+            target,
+            guard,
+            synchronisation,
+            assignment,
+        });
+    }
+
+    /// Edges from source to target for an action: the guard is on the first
+    /// edge, and each effect is sent on its own edge.
+    fn add_action(
+        &mut self,
+        ids: &mut usize,
+        skillset: &Skillset,
+        action: &Action,
+        source: usize,
+        target: usize,
+        assignment: Option<String>,
+    ) {
+        let guard = Some(uppaal_expr(skillset, &action.guard(skillset)));
+        let effects = action.effects(skillset);
+        if effects.is_empty() {
+            self.add_edge(source, target, guard, None, assignment);
+            return;
+        }
+        let mut current = source;
+        for (i, effect) in effects.iter().enumerate() {
+            let last = i + 1 == effects.len();
+            let next = if last {
+                target
+            } else {
+                self.add_location(ids, None, true)
+            };
+            let sync = Some(format!(
+                "{}!",
+                channel_name(skillset, effect.state().resolved())
+            ));
+            let guard = if i == 0 { guard.clone() } else { None };
+            let assign = if last { assignment.clone() } else { None };
+            self.add_edge(current, next, guard, sync, assign);
+            current = next;
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        let mut s = String::from("\t<template>\n");
+        s.push_str(&format!("\t\t<name>{}</name>\n", self.name));
+        s.push_str(&format!(
+            "\t\t<declaration>{}</declaration>\n",
+            escape(&self.declaration)
+        ));
+        for x in self.locations.iter() {
+            s.push_str(&format!("\t\t<location id=\"id{}\">\n", x.id));
+            if let Some(name) = &x.name {
+                s.push_str(&format!("\t\t\t<name>{}</name>\n", name));
+            }
+            if let Some(invariant) = &x.invariant {
+                s.push_str(&format!(
+                    "\t\t\t<label kind=\"invariant\">{}</label>\n",
+                    escape(invariant)
+                ));
+            }
+            if x.committed {
+                s.push_str("\t\t\t<committed/>\n");
+            }
+            s.push_str("\t\t</location>\n");
+        }
+        s.push_str(&format!("\t\t<init ref=\"id{}\"/>\n", self.init));
+        for x in self.edges.iter() {
+            s.push_str("\t\t<transition>\n");
+            s.push_str(&format!("\t\t\t<source ref=\"id{}\"/>\n", x.source));
+            s.push_str(&format!("\t\t\t<target ref=\"id{}\"/>\n", x.target));
+            if let Some(guard) = &x.guard {
+                s.push_str(&format!(
+                    "\t\t\t<label kind=\"guard\">{}</label>\n",
+                    escape(guard)
+                ));
+            }
+            if let Some(sync) = &x.synchronisation {
+                s.push_str(&format!(
+                    "\t\t\t<label kind=\"synchronisation\">{}</label>\n",
+                    escape(sync)
+                ));
+            }
+            if let Some(assign) = &x.assignment {
+                s.push_str(&format!(
+                    "\t\t\t<label kind=\"assignment\">{}</label>\n",
+                    escape(assign)
+                ));
+            }
+            s.push_str("\t\t</transition>\n");
+        }
+        s.push_str("\t</template>\n");
+        s
+    }
+}
+
+fn resource_template(ids: &mut usize, skillset: &Skillset, resource: &Resource) -> Template {
+    let mut template = Template::new(format!("{}_resource", resource.name()));
+    let mut locations = Vec::new();
+    for state in resource.states() {
+        let id = template.add_location(ids, Some(state.name().into()), false);
+        if state.id() == resource.initial() {
+            template.init = id;
+        }
+        locations.push(id);
+    }
+    let assign = |dst: StateId| {
+        Some(format!(
+            "{} = {}",
+            resource.name(),
+            state_name(skillset, dst)
+        ))
+    };
+    // Effect on the current state
+    for state in resource.states() {
+        let id = locations[state.id().index()];
+        let sync = Some(format!("{}?", channel_name(skillset, state.id())));
+        template.add_edge(id, id, None, sync, None);
+    }
+    // Transitions
+    for transition in resource.transitions_list() {
+        let src = transition.src().resolved();
+        let dst = transition.dst().resolved();
+        let sync = Some(format!("{}?", channel_name(skillset, dst)));
+        template.add_edge(
+            locations[src.index()],
+            locations[dst.index()],
+            None,
+            sync,
+            assign(dst),
+        );
+    }
+    template
+}
+
+fn event_template(ids: &mut usize, skillset: &Skillset) -> Template {
+    let mut template = Template::new("events");
+    let id = template.add_location(ids, Some("wait".into()), false);
+    template.init = id;
+    for event in skillset.events() {
+        template.add_action(ids, skillset, &Action::Event(event.id()), id, id, None);
+    }
+    template
+}
+
+fn skill_template(ids: &mut usize, skillset: &Skillset, skill: &Skill) -> Template {
+    let mut template = Template::new(format!("{}_skill", skill.name()));
+    let idle = template.add_location(ids, Some("idle".into()), false);
+    let running = template.add_location(ids, Some("running".into()), false);
+    template.init = idle;
+    let clock = format!("{}_clock", skill.name());
+    let reset = skill.progress().as_ref().map(|_| format!("{} = 0", clock));
+    // Progress
+    if let Some(progress) = skill.progress() {
+        let period = progress.period().as_millis();
+        template.declaration = format!("clock {};", clock);
+        if let Some(location) = template.locations.iter_mut().find(|x| x.id == running) {
+            location.invariant = Some(format!("{} <= {}", clock, period));
+        }
+        template.add_edge(
+            running,
+            running,
+            Some(format!("{} == {}", clock, period)),
+            Some(format!("{}_progress!", skill.name())),
+            reset.clone(),
+        );
+    }
+    // Lifecycle
+    for action in skillset.actions() {
+        if action.skill() != Some(skill.id()) {
+            continue;
+        }
+        match action {
+            Action::Start(_) => {
+                template.add_action(ids, skillset, &action, idle, running, reset.clone())
+            }
+            _ => template.add_action(ids, skillset, &action, running, idle, None),
+        }
+    }
+    template
+}
+
+//------------------------- Skillset -------------------------
+
+/// Translate a resolved skillset into an UPPAAL timed automata network.
+/// Time is expressed in milliseconds.
+pub fn to_uppaal(skillset: &Skillset) -> String {
+    let mut ids = 0;
+    let mut templates = Vec::new();
+    for resource in skillset.resources() {
+        templates.push(resource_template(&mut ids, skillset, resource));
+    }
+    if !skillset.events().is_empty() {
+        templates.push(event_template(&mut ids, skillset));
+    }
+    for skill in skillset.skills() {
+        templates.push(skill_template(&mut ids, skillset, skill));
+    }
+    //
+    let mut s = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    s.push_str("<!DOCTYPE nta PUBLIC '-//Uppaal Team//DTD Flat System 1.1//EN' 'http://www.it.uu.se/research/group/darts/uppaal/flat-1_2.dtd'>\n");
+    s.push_str("<nta>\n");
+    // Declaration
+    let mut declaration = format!("// skillset {}\n", skillset.name());
+    for resource in skillset.resources() {
+        for state in resource.states() {
+            declaration.push_str(&format!(
+                "const int {} = {};\n",
+                state_name(skillset, state.id()),
+                state.id().index()
+            ));
+        }
+        declaration.push_str(&format!(
+            "int {} = {};\n",
+            resource.name(),
+            state_name(skillset, resource.initial())
+        ));
+        let channels: Vec<String> = resource
+            .states()
+            .iter()
+            .map(|x| channel_name(skillset, x.id()))
+            .collect();
+        declaration.push_str(&format!("chan {};\n", channels.join(", ")));
+    }
+    for skill in skillset.skills() {
+        if skill.progress().is_some() {
+            declaration.push_str(&format!("broadcast chan {}_progress;\n", skill.name()));
+        }
+    }
+    s.push_str(&format!(
+        "\t<declaration>{}</declaration>\n",
+        escape(&declaration)
+    ));
+    // Templates
+    for x in templates.iter() {
+        s.push_str(&x.to_xml());
+    }
+    // System
+    let names: Vec<String> = templates.iter().map(|x| x.name.clone()).collect();
+    s.push_str(&format!(
+        "\t<system>system {};</system>\n",
+        names.join(", ")
+    ));
+    // Queries
+    s.push_str("\t<queries>\n");
+    let mut queries = vec![("A[] not deadlock".to_string(), "no deadlock".to_string())];
+    for constraint in skillset.constraints() {
+        queries.push((
+            format!("A[] {}", uppaal_expr(skillset, constraint.expr())),
+            format!("constraint {} always holds", constraint),
+        ));
+    }
+    for (formula, comment) in queries {
+        s.push_str("\t\t<query>\n");
+        s.push_str(&format!("\t\t\t<formula>{}</formula>\n", escape(&formula)));
+        s.push_str(&format!("\t\t\t<comment>{}</comment>\n", escape(&comment)));
+        s.push_str("\t\t</query>\n");
+    }
+    s.push_str("\t</queries>\n");
+    s.push_str("</nta>\n");
+    s
+}
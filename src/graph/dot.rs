@@ -0,0 +1,129 @@
+use super::*;
+
+// Each resource is a cluster of its states, with a point marking the initial
+// state; the declared transitions are labelled with the actions that can
+// perform them, and dashed when there is none.
+// Each skill is a cluster going from idle to running on start, then from
+// running to one node per invariant violation, interrupt, success and
+// failure.
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\\\""))
+}
+
+fn lines(v: &[String]) -> String {
+    v.iter()
+        .map(|x| x.replace('"', "\\\""))
+        .collect::<Vec<_>>()
+        .join("\\n")
+}
+
+fn resource_cluster(skillset: &Skillset, resource: &Resource) -> String {
+    let node = |id: StateId| {
+        quote(&format!(
+            "{}.{}",
+            resource.name(),
+            skillset.get(id).unwrap()
+        ))
+    };
+    let mut s = format!(
+        "\tsubgraph {} {{\n\t\tlabel = {};\n",
+        quote(&format!("cluster_resource_{}", resource.name())),
+        quote(&format!("resource {}", resource.name()))
+    );
+    let initial = quote(&format!("{}.initial", resource.name()));
+    s.push_str(&format!("\t\t{} [shape = point];\n", initial));
+    for state in resource.states() {
+        s.push_str(&format!(
+            "\t\t{} [label = {}];\n",
+            node(state.id()),
+            quote(state.name())
+        ));
+    }
+    s.push_str(&format!(
+        "\t\t{} -> {};\n",
+        initial,
+        node(resource.initial())
+    ));
+    for transition in resource.transitions_list() {
+        let actions = transition_labels(skillset, &transition);
+        let attributes = if actions.is_empty() {
+            "style = dashed".to_string()
+        } else {
+            format!("label = \"{}\"", lines(&actions))
+        };
+        s.push_str(&format!(
+            "\t\t{} -> {} [{}];\n",
+            node(transition.src().resolved()),
+            node(transition.dst().resolved()),
+            attributes
+        ));
+    }
+    s.push_str("\t}\n");
+    s
+}
+
+fn skill_cluster(skillset: &Skillset, skill: &Skill) -> String {
+    let node = |x: &str| quote(&format!("{}.{}", skill.name(), x));
+    let mut s = format!(
+        "\tsubgraph {} {{\n\t\tlabel = {};\n",
+        quote(&format!("cluster_skill_{}", skill.name())),
+        quote(&format!("skill {}", skill.name()))
+    );
+    // Idle and running
+    s.push_str(&format!("\t\t{} [label = \"idle\"];\n", node("idle")));
+    let mut running = vec!["running".to_string()];
+    running.extend(invariants(skillset, skill));
+    s.push_str(&format!(
+        "\t\t{} [shape = box, label = \"{}\"];\n",
+        node("running"),
+        lines(&running)
+    ));
+    s.push_str(&format!(
+        "\t\t{} -> {} [label = \"{}\"];\n",
+        node("idle"),
+        node("running"),
+        lines(&start(skillset, skill))
+    ));
+    // Modes
+    for mode in modes(skillset, skill) {
+        let attributes = match mode.kind {
+            Kind::Invariant => "color = red, style = dashed",
+            Kind::Interrupt => "color = orange",
+            Kind::Success => "color = darkgreen",
+            Kind::Failure => "color = red",
+        };
+        let id = node(&mode.title());
+        s.push_str(&format!(
+            "\t\t{} [shape = doublecircle, label = {}, {}];\n",
+            id,
+            quote(&mode.title()),
+            attributes
+        ));
+        s.push_str(&format!(
+            "\t\t{} -> {} [label = \"{}\", {}];\n",
+            node("running"),
+            id,
+            lines(&mode.label()),
+            attributes
+        ));
+    }
+    s.push_str("\t}\n");
+    s
+}
+
+/// The state machines of the resources and the lifecycles of the skills of a
+/// resolved skillset in the Graphviz DOT language.
+pub fn to_dot(skillset: &Skillset) -> String {
+    let mut s = format!("digraph {} {{\n", quote(skillset.name()));
+    s.push_str("\tnode [fontname = \"Helvetica\"];\n");
+    s.push_str("\tedge [fontname = \"Helvetica\", fontsize = 10];\n");
+    for resource in skillset.resources() {
+        s.push_str(&resource_cluster(skillset, resource));
+    }
+    for skill in skillset.skills() {
+        s.push_str(&skill_cluster(skillset, skill));
+    }
+    s.push_str("}\n");
+    s
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+// Same diagram as PlantUML, Mermaid having no dashed transitions: the
+// transitions that no action can perform have no label. The terminal states
+// of the skills are styled by kind.
+
+fn lines(v: &[String]) -> String {
+    v.join("<br/>")
+}
+
+fn resource_state(skillset: &Skillset, resource: &Resource) -> String {
+    let id = |x: StateId| format!("{}_{}", resource.name(), skillset.get(x).unwrap());
+    let mut s = format!("\tstate resource_{} {{\n", resource.name());
+    for state in resource.states() {
+        s.push_str(&format!("\t\tstate \"{}\" as {}\n", state, id(state.id())));
+    }
+    s.push_str(&format!("\t\t[*] --> {}\n", id(resource.initial())));
+    for transition in resource.transitions_list() {
+        let src = id(transition.src().resolved());
+        let dst = id(transition.dst().resolved());
+        let actions = transition_labels(skillset, &transition);
+        if actions.is_empty() {
+            s.push_str(&format!("\t\t{} --> {}\n", src, dst));
+        } else {
+            s.push_str(&format!("\t\t{} --> {} : {}\n", src, dst, lines(&actions)));
+        }
+    }
+    s.push_str("\t}\n");
+    s
+}
+
+fn mode_id(skill: &Skill, x: &str) -> String {
+    format!("{}_{}", skill.name(), x.replace(' ', "_"))
+}
+
+fn skill_state(skillset: &Skillset, skill: &Skill) -> String {
+    let id = |x: &str| mode_id(skill, x);
+    let mut s = format!("\tstate skill_{} {{\n", skill.name());
+    s.push_str(&format!("\t\tstate \"idle\" as {}\n", id("idle")));
+    s.push_str(&format!("\t\tstate \"running\" as {}\n", id("running")));
+    for invariant in invariants(skillset, skill) {
+        s.push_str(&format!("\t\t{} : {}\n", id("running"), invariant));
+    }
+    s.push_str(&format!("\t\t[*] --> {}\n", id("idle")));
+    s.push_str(&format!(
+        "\t\t{} --> {} : {}\n",
+        id("idle"),
+        id("running"),
+        lines(&start(skillset, skill))
+    ));
+    for mode in modes(skillset, skill) {
+        let title = mode.title();
+        s.push_str(&format!("\t\tstate \"{}\" as {}\n", title, id(&title)));
+        let label = mode.label();
+        if label.is_empty() {
+            s.push_str(&format!("\t\t{} --> {}\n", id("running"), id(&title)));
+        } else {
+            s.push_str(&format!(
+                "\t\t{} --> {} : {}\n",
+                id("running"),
+                id(&title),
+                lines(&label)
+            ));
+        }
+    }
+    s.push_str("\t}\n");
+    s
+}
+
+/// The state machines of the resources and the lifecycles of the skills of a
+/// resolved skillset as a Mermaid state diagram.
+pub fn to_mermaid(skillset: &Skillset) -> String {
+    let mut s = format!(
+        "---\ntitle: skillset {}\n---\nstateDiagram-v2\n",
+        skillset.name()
+    );
+    s.push_str("\tclassDef success fill:#bfb\n");
+    s.push_str("\tclassDef failure fill:#fbb\n");
+    s.push_str("\tclassDef interrupt fill:#fdb\n");
+    for resource in skillset.resources() {
+        s.push_str(&resource_state(skillset, resource));
+    }
+    for skill in skillset.skills() {
+        s.push_str(&skill_state(skillset, skill));
+    }
+    // Styles, that cannot be given inside composite states
+    for skill in skillset.skills() {
+        for mode in modes(skillset, skill) {
+            let class = match mode.kind {
+                Kind::Invariant | Kind::Failure => "failure",
+                Kind::Interrupt => "interrupt",
+                Kind::Success => "success",
+            };
+            s.push_str(&format!(
+                "\tclass {} {}\n",
+                mode_id(skill, &mode.title()),
+                class
+            ));
+        }
+    }
+    s
+}
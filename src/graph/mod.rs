@@ -0,0 +1,171 @@
+pub mod dot;
+pub use dot::*;
+
+pub mod plantuml;
+pub use plantuml::*;
+
+pub mod mermaid;
+pub use mermaid::*;
+
+use crate::model::*;
+
+/// Short label of an action, the name of an event or skill.mode.
+pub fn action_label(skillset: &Skillset, action: &Action) -> String {
+    let skill = |id: SkillId| skillset.get(id).unwrap().name().to_string();
+    match action {
+        Action::Event(id) => skillset.get(*id).unwrap().name().to_string(),
+        Action::Start(id) => format!("{}.start", skill(*id)),
+        Action::Invariant(id) => format!(
+            "{}.{}",
+            skill(id.skill()),
+            skillset.get(*id).unwrap().name()
+        ),
+        Action::Interrupt(id) => format!("{}.interrupt", skill(*id)),
+        Action::Success(id) => format!(
+            "{}.{}",
+            skill(id.skill()),
+            skillset.get(*id).unwrap().name()
+        ),
+        Action::Failure(id) => format!(
+            "{}.{}",
+            skill(id.skill()),
+            skillset.get(*id).unwrap().name()
+        ),
+    }
+}
+
+/// The actions whose effects can perform the transition, that is setting the
+/// resource to the destination while the guard allows it to be in the source.
+pub fn transition_actions(skillset: &Skillset, transition: &Transition) -> Vec<Action> {
+    let src = transition.src().resolved();
+    let dst = transition.dst().resolved();
+    skillset
+        .actions()
+        .into_iter()
+        .filter(|action| {
+            let target = action
+                .effects(skillset)
+                .iter()
+                .rev()
+                .find(|x| x.resource().resolved() == dst.resource());
+            match target {
+                Some(effect) if effect.state().resolved() == dst => {
+                    let in_src = Expr::ResourceEq(
+                        Reference::Resolved(src.resource()),
+                        Reference::Resolved(src),
+                    );
+                    Expr::And(Box::new(action.guard(skillset)), Box::new(in_src))
+                        .is_satisfiable(skillset)
+                }
+                _ => false,
+            }
+        })
+        .collect()
+}
+
+/// The labels of the actions that can perform the transition.
+fn transition_labels(skillset: &Skillset, transition: &Transition) -> Vec<String> {
+    transition_actions(skillset, transition)
+        .iter()
+        .map(|x| action_label(skillset, x))
+        .collect()
+}
+
+//------------------------- Lifecycle -------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Kind {
+    Invariant,
+    Interrupt,
+    Success,
+    Failure,
+}
+
+/// A way for a running skill to stop, with its conditions and effects as
+/// text.
+struct Mode {
+    kind: Kind,
+    name: String,
+    conditions: Vec<String>,
+    effects: Vec<String>,
+}
+
+impl Mode {
+    /// The kind and name of the mode, such as "success arrived".
+    fn title(&self) -> String {
+        match self.kind {
+            Kind::Invariant => format!("invariant {}", self.name),
+            Kind::Interrupt => "interrupt".into(),
+            Kind::Success => format!("success {}", self.name),
+            Kind::Failure => format!("failure {}", self.name),
+        }
+    }
+
+    /// The conditions then the effects.
+    fn label(&self) -> Vec<String> {
+        let mut v = self.conditions.clone();
+        v.extend(self.effects.clone());
+        v
+    }
+}
+
+fn texts<T: ToLang>(skillset: &Skillset, v: &[T]) -> Vec<String> {
+    v.iter().map(|x| x.to_lang(skillset)).collect()
+}
+
+/// The start, preconditions then effects.
+fn start(skillset: &Skillset, skill: &Skill) -> Vec<String> {
+    let mut v = vec!["start".to_string()];
+    v.extend(preconditions(skillset, skill));
+    v.extend(texts(skillset, skill.start()));
+    v
+}
+
+fn preconditions(skillset: &Skillset, skill: &Skill) -> Vec<String> {
+    texts(skillset, skill.preconditions())
+}
+
+fn invariants(skillset: &Skillset, skill: &Skill) -> Vec<String> {
+    skill
+        .invariants()
+        .iter()
+        .map(|x| format!("{}: {}", x.name(), x.guard().to_lang(skillset)))
+        .collect()
+}
+
+fn modes(skillset: &Skillset, skill: &Skill) -> Vec<Mode> {
+    let mut v = Vec::new();
+    for x in skill.invariants() {
+        v.push(Mode {
+            kind: Kind::Invariant,
+            name: x.name().to_string(),
+            conditions: vec![Expr::Not(Box::new(x.guard().clone())).to_lang(skillset)],
+            effects: texts(skillset, x.effects()),
+        });
+    }
+    if let Some(x) = skill.interrupt() {
+        v.push(Mode {
+            kind: Kind::Interrupt,
+            name: "interrupt".into(),
+            conditions: texts(skillset, x.postconditions()),
+            effects: texts(skillset, x.effects()),
+        });
+    }
+    for x in skill.successes() {
+        v.push(Mode {
+            kind: Kind::Success,
+            name: x.name().to_string(),
+            conditions: texts(skillset, x.postconditions()),
+            effects: texts(skillset, x.effects()),
+        });
+    }
+    for x in skill.failures() {
+        v.push(Mode {
+            kind: Kind::Failure,
+            name: x.name().to_string(),
+            conditions: texts(skillset, x.postconditions()),
+            effects: texts(skillset, x.effects()),
+        });
+    }
+    v
+}
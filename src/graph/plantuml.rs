@@ -0,0 +1,99 @@
+use super::*;
+
+// Each resource is a composite state of its states, the transitions that no
+// action can perform being dashed. Each skill is a composite state going from
+// idle to running, then to one state per invariant violation, interrupt,
+// success and failure.
+
+fn lines(v: &[String]) -> String {
+    v.join("\\n")
+}
+
+fn resource_state(skillset: &Skillset, resource: &Resource) -> String {
+    let id = |x: StateId| format!("{}_{}", resource.name(), skillset.get(x).unwrap());
+    let mut s = format!(
+        "state \"resource {}\" as resource_{} {{\n",
+        resource.name(),
+        resource.name()
+    );
+    for state in resource.states() {
+        s.push_str(&format!("\tstate \"{}\" as {}\n", state, id(state.id())));
+    }
+    s.push_str(&format!("\t[*] --> {}\n", id(resource.initial())));
+    for transition in resource.transitions_list() {
+        let src = id(transition.src().resolved());
+        let dst = id(transition.dst().resolved());
+        let actions = transition_labels(skillset, &transition);
+        if actions.is_empty() {
+            s.push_str(&format!("\t{} -[dashed]-> {}\n", src, dst));
+        } else {
+            s.push_str(&format!("\t{} --> {} : {}\n", src, dst, lines(&actions)));
+        }
+    }
+    s.push_str("}\n");
+    s
+}
+
+fn skill_state(skillset: &Skillset, skill: &Skill) -> String {
+    let id = |x: &str| format!("{}_{}", skill.name(), x.replace(' ', "_"));
+    let mut s = format!(
+        "state \"skill {}\" as skill_{} {{\n",
+        skill.name(),
+        skill.name()
+    );
+    s.push_str(&format!("\tstate \"idle\" as {}\n", id("idle")));
+    s.push_str(&format!("\tstate \"running\" as {}\n", id("running")));
+    for invariant in invariants(skillset, skill) {
+        s.push_str(&format!("\t{} : {}\n", id("running"), invariant));
+    }
+    s.push_str(&format!("\t[*] --> {}\n", id("idle")));
+    s.push_str(&format!(
+        "\t{} --> {} : {}\n",
+        id("idle"),
+        id("running"),
+        lines(&start(skillset, skill))
+    ));
+    for mode in modes(skillset, skill) {
+        let color = match mode.kind {
+            Kind::Invariant | Kind::Failure => "#pink",
+            Kind::Interrupt => "#orange",
+            Kind::Success => "#palegreen",
+        };
+        let title = mode.title();
+        s.push_str(&format!(
+            "\tstate \"{}\" as {} {}\n",
+            title,
+            id(&title),
+            color
+        ));
+        let label = mode.label();
+        if label.is_empty() {
+            s.push_str(&format!("\t{} --> {}\n", id("running"), id(&title)));
+        } else {
+            s.push_str(&format!(
+                "\t{} --> {} : {}\n",
+                id("running"),
+                id(&title),
+                lines(&label)
+            ));
+        }
+    }
+    s.push_str("}\n");
+    s
+}
+
+/// The state machines of the resources and the lifecycles of the skills of a
+/// resolved skillset as a PlantUML state diagram.
+pub fn to_plantuml(skillset: &Skillset) -> String {
+    let mut s = format!("@startuml\ntitle skillset {}\n\n", skillset.name());
+    for resource in skillset.resources() {
+        s.push_str(&resource_state(skillset, resource));
+        s.push('\n');
+    }
+    for skill in skillset.skills() {
+        s.push_str(&skill_state(skillset, skill));
+        s.push('\n');
+    }
+    s.push_str("@enduml\n");
+    s
+}
@@ -4,8 +4,15 @@ extern crate lalrpop_util;
 #[macro_use]
 extern crate log;
 
+pub mod analysis;
+pub mod doc;
+pub mod export;
+pub mod graph;
 pub mod model;
 pub mod parser;
+pub mod planner;
+pub mod runtime;
+pub mod symbolic;
 use crate::model::Skillset;
 
 pub fn load_skillset(filename: &str) -> Result<Skillset, parser::RlError> {
@@ -34,7 +41,7 @@ pub fn load_skillset(filename: &str) -> Result<Skillset, parser::RlError> {
         }
         Err(e) => {
             error!("{}", e);
-            return Err(e);
+            Err(e)
         }
     }
 }
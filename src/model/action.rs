@@ -0,0 +1,196 @@
+use super::*;
+use crate::parser::Position;
+
+/// A discrete step of the skillset semantics: an event, or a step of a skill
+/// lifecycle (start, invariant violation, interrupt, success or failure).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Action {
+    Event(EventId),
+    Start(SkillId),
+    Invariant(InvariantId),
+    Interrupt(SkillId),
+    Success(SuccessId),
+    Failure(FailureId),
+}
+
+impl Action {
+    /// The skill whose lifecycle this action belongs to, if any.
+    pub fn skill(&self) -> Option<SkillId> {
+        match self {
+            Action::Event(_) => None,
+            Action::Start(id) => Some(*id),
+            Action::Invariant(id) => Some(id.skill()),
+            Action::Interrupt(id) => Some(*id),
+            Action::Success(id) => Some(id.skill()),
+            Action::Failure(id) => Some(id.skill()),
+        }
+    }
+
+    /// Is the skill running after this action (None for events).
+    pub fn running_after(&self) -> Option<bool> {
+        match self {
+            Action::Event(_) => None,
+            Action::Start(_) => Some(true),
+            _ => Some(false),
+        }
+    }
+
+    /// Identifier usable in generated code, unique within a skillset.
+    pub fn name(&self, skillset: &Skillset) -> String {
+        match self {
+            Action::Event(id) => format!("event_{}", skillset.get(*id).unwrap().name()),
+            Action::Start(id) => format!("{}_start", skillset.get(*id).unwrap().name()),
+            Action::Invariant(id) => format!(
+                "{}_invariant_{}",
+                skillset.get(id.skill()).unwrap().name(),
+                skillset.get(*id).unwrap().name()
+            ),
+            Action::Interrupt(id) => format!("{}_interrupt", skillset.get(*id).unwrap().name()),
+            Action::Success(id) => format!(
+                "{}_success_{}",
+                skillset.get(id.skill()).unwrap().name(),
+                skillset.get(*id).unwrap().name()
+            ),
+            Action::Failure(id) => format!(
+                "{}_failure_{}",
+                skillset.get(id.skill()).unwrap().name(),
+                skillset.get(*id).unwrap().name()
+            ),
+        }
+    }
+
+    /// Condition on the resources for the action to be enabled.
+    /// The skill status (idle for start, running otherwise) is not included.
+    ///
+    /// A skill can only be interrupted or terminate while its invariants and
+    /// the postconditions of the mode hold, before the effects of the mode.
+    pub fn guard(&self, skillset: &Skillset) -> Expr {
+        match self {
+            Action::Event(id) => match skillset.get(*id).unwrap().guard() {
+                Some(guard) => guard.clone(),
+                None => Expr::True,
+            },
+            Action::Start(id) => Expr::and_all(
+                skillset
+                    .get(*id)
+                    .unwrap()
+                    .preconditions()
+                    .iter()
+                    .map(|x| x.expr().clone()),
+            ),
+            Action::Invariant(id) => {
+                Expr::Not(Box::new(skillset.get(*id).unwrap().guard().clone()))
+            }
+            _ => {
+                let skill = skillset.get(self.skill().unwrap()).unwrap();
+                Expr::and_all(
+                    skill
+                        .invariants()
+                        .iter()
+                        .map(|x| x.guard().clone())
+                        .chain([self.postconditions(skillset)]),
+                )
+            }
+        }
+    }
+
+    /// The postconditions of an interrupt, success or failure, true
+    /// otherwise. They hold when the skill terminates, before its effects.
+    pub fn postconditions(&self, skillset: &Skillset) -> Expr {
+        let postconditions: &[Postcondition] = match self {
+            Action::Interrupt(id) => match skillset.get(*id).unwrap().interrupt() {
+                Some(interrupt) => interrupt.postconditions(),
+                None => &[],
+            },
+            Action::Success(id) => skillset.get(*id).unwrap().postconditions(),
+            Action::Failure(id) => skillset.get(*id).unwrap().postconditions(),
+            _ => &[],
+        };
+        Expr::and_all(postconditions.iter().map(|x| x.expr().clone()))
+    }
+
+    /// The states of the resources of the postconditions where they hold, as
+    /// effects. Empty when the postconditions cannot hold, a single empty
+    /// list without postconditions.
+    ///
+    /// The planning abstractions, that do not consider events, assume that a
+    /// running skill brings these resources in one of them before it
+    /// terminates.
+    pub fn terminations(&self, skillset: &Skillset) -> Vec<Vec<Effect>> {
+        let postconditions = self.postconditions(skillset);
+        let resources = postconditions.resources();
+        Valuation::all_on(skillset, &resources)
+            .into_iter()
+            .filter(|x| postconditions.eval(x))
+            .map(|x| {
+                resources
+                    .iter()
+                    .map(|id| {
+                        Effect::new(
+                            Reference::Resolved(*id),
+                            Reference::Resolved(x.get(*id)),
+                            None,
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Effects applied on the resources when the action occurs.
+    pub fn effects<'a>(&self, skillset: &'a Skillset) -> &'a [Effect] {
+        match self {
+            Action::Event(id) => skillset.get(*id).unwrap().effects(),
+            Action::Start(id) => skillset.get(*id).unwrap().start(),
+            Action::Invariant(id) => skillset.get(*id).unwrap().effects(),
+            Action::Interrupt(id) => match skillset.get(*id).unwrap().interrupt() {
+                Some(interrupt) => interrupt.effects(),
+                None => &[],
+            },
+            Action::Success(id) => skillset.get(*id).unwrap().effects(),
+            Action::Failure(id) => skillset.get(*id).unwrap().effects(),
+        }
+    }
+
+    pub fn position(&self, skillset: &Skillset) -> Option<Position> {
+        match self {
+            Action::Event(id) => skillset.get(*id).unwrap().position(),
+            Action::Start(id) => skillset.get(*id).unwrap().position(),
+            Action::Invariant(id) => skillset.get(*id).unwrap().position(),
+            Action::Interrupt(id) => {
+                let skill = skillset.get(*id).unwrap();
+                match skill.interrupt() {
+                    Some(interrupt) => interrupt.position(),
+                    None => skill.position(),
+                }
+            }
+            Action::Success(id) => skillset.get(*id).unwrap().position(),
+            Action::Failure(id) => skillset.get(*id).unwrap().position(),
+        }
+    }
+}
+
+impl ToLang for Action {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        match self {
+            Action::Event(id) => format!("event {}", skillset.get(*id).unwrap()),
+            Action::Start(id) => format!("start {}", skillset.get(*id).unwrap()),
+            Action::Invariant(id) => format!(
+                "invariant {}.{}",
+                skillset.get(id.skill()).unwrap(),
+                skillset.get(*id).unwrap()
+            ),
+            Action::Interrupt(id) => format!("interrupt {}", skillset.get(*id).unwrap()),
+            Action::Success(id) => format!(
+                "success {}.{}",
+                skillset.get(id.skill()).unwrap(),
+                skillset.get(*id).unwrap()
+            ),
+            Action::Failure(id) => format!(
+                "failure {}.{}",
+                skillset.get(id.skill()).unwrap(),
+                skillset.get(*id).unwrap()
+            ),
+        }
+    }
+}
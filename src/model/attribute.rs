@@ -0,0 +1,80 @@
+use crate::parser::Position;
+
+/// Metadata of a declaration: `@key` or `@key("value")`.
+#[derive(Debug, Clone)]
+pub struct Attribute {
+    key: String,
+    value: Option<String>,
+    position: Option<Position>,
+}
+
+impl Attribute {
+    pub fn new<S: Into<String>>(key: S, value: Option<String>, position: Option<Position>) -> Self {
+        Self {
+            key: key.into(),
+            value,
+            position,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position.clone()
+    }
+}
+
+impl std::fmt::Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "@{}(\"{}\")", self.key, value),
+            None => write!(f, "@{}", self.key),
+        }
+    }
+}
+
+/// The attributes of a declaration, in the order they are written.
+#[derive(Debug, Clone, Default)]
+pub struct Attributes(Vec<Attribute>);
+
+impl Attributes {
+    pub fn new(attributes: Vec<Attribute>) -> Self {
+        Self(attributes)
+    }
+
+    pub fn all(&self) -> &Vec<Attribute> {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Attribute> {
+        self.0.iter().find(|x| x.key() == key)
+    }
+
+    pub fn has(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(|x| x.value())
+    }
+}
+
+/// The attributes each followed by a space, to be written before a name.
+impl std::fmt::Display for Attributes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for x in self.0.iter() {
+            write!(f, "{} ", x)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::parser::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct ConstraintId(pub usize);
+impl Id for ConstraintId {
+    fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A condition on the resources that must hold in every reachable state.
+#[derive(Debug, Clone)]
+pub struct Constraint {
+    id: ConstraintId,
+    name: String,
+    expr: Expr,
+    attributes: Attributes,
+    position: Option<Position>,
+}
+
+impl Constraint {
+    pub fn new<S: Into<String>>(name: S, expr: Expr, position: Option<Position>) -> Self {
+        let id = ConstraintId::default();
+        let name = name.into();
+        Self {
+            id,
+            name,
+            expr,
+            attributes: Attributes::default(),
+            position,
+        }
+    }
+
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    //---------- Resolve ----------
+
+    pub fn resolve_resource(&mut self, map: &HashMap<String, ResourceId>) -> Result<(), RlError> {
+        self.expr.resolve_resource(map)
+    }
+
+    pub fn resolve_state(&mut self, map: &HashMap<String, StateId>) -> Result<(), RlError> {
+        self.expr.resolve_state(map)
+    }
+}
+
+impl Named<ConstraintId> for Constraint {
+    fn id(&self) -> ConstraintId {
+        self.id
+    }
+
+    fn set_id(&mut self, id: ConstraintId) {
+        self.id = id;
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn position(&self) -> Option<Position> {
+        self.position.clone()
+    }
+}
+
+impl ToLang for Constraint {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        format!(
+            "{}{}: {}\n",
+            self.attributes,
+            self.name,
+            self.expr.to_lang(skillset)
+        )
+    }
+}
+
+impl std::fmt::Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
@@ -17,6 +17,8 @@ pub struct Data {
     name: String,
     rl_type: Reference<TypeId>,
     period: Option<Duration>,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -34,10 +36,28 @@ impl Data {
             name,
             rl_type,
             period,
+            description: None,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
+    }
+
     pub fn rl_type(&self) -> &Reference<TypeId> {
         &self.rl_type
     }
@@ -89,14 +109,26 @@ impl Named<DataId> for Data {
 
 impl ToLang for Data {
     fn to_lang(&self, skillset: &Skillset) -> String {
+        let description = match &self.description {
+            Some(description) => format!(" description \"{}\"", description),
+            None => String::new(),
+        };
         match self.period {
             Some(period) => format!(
-                "{}: {} period {} ms\n",
+                "{}{}: {} period {} ms{}\n",
+                self.attributes,
+                self.name,
+                self.rl_type.to_lang(skillset),
+                period.as_millis(),
+                description
+            ),
+            None => format!(
+                "{}{}: {}{}\n",
+                self.attributes,
                 self.name,
                 self.rl_type.to_lang(skillset),
-                period.as_millis()
+                description
             ),
-            None => format!("{}: {}\n", self.name, self.rl_type.to_lang(skillset)),
         }
     }
 }
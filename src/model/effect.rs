@@ -6,11 +6,20 @@ use std::collections::HashMap;
 pub struct Effect {
     resource: Reference<ResourceId>,
     state: Reference<StateId>,
+    position: Option<Position>,
 }
 
 impl Effect {
-    pub fn new(resource: Reference<ResourceId>, state: Reference<StateId>) -> Self {
-        Self { resource, state }
+    pub fn new(
+        resource: Reference<ResourceId>,
+        state: Reference<StateId>,
+        position: Option<Position>,
+    ) -> Self {
+        Self {
+            resource,
+            state,
+            position,
+        }
     }
 
     pub fn resource(&self) -> &Reference<ResourceId> {
@@ -21,6 +30,10 @@ impl Effect {
         &self.state
     }
 
+    pub fn position(&self) -> Option<Position> {
+        self.position.clone()
+    }
+
     //---------- Resolve ----------
 
     pub fn resolve_resource(&mut self, map: &HashMap<String, ResourceId>) -> Result<(), RlError> {
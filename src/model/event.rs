@@ -16,6 +16,9 @@ pub struct Event {
     name: String,
     guard: Option<Expr>,
     effects: Vec<Effect>,
+    likelihood: Option<Likelihood>,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -33,10 +36,29 @@ impl Event {
             name,
             guard,
             effects,
+            likelihood: None,
+            description: None,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
+    }
+
     pub fn guard(&self) -> &Option<Expr> {
         &self.guard
     }
@@ -45,14 +67,19 @@ impl Event {
         &self.effects
     }
 
+    pub fn likelihood(&self) -> Option<Likelihood> {
+        self.likelihood
+    }
+
+    pub fn set_likelihood(&mut self, likelihood: Likelihood) {
+        self.likelihood = Some(likelihood);
+    }
+
     //---------- Resolve ----------
 
     pub fn resolve_resource(&mut self, map: &HashMap<String, ResourceId>) -> Result<(), RlError> {
-        match &mut self.guard {
-            Some(e) => {
-                e.resolve_resource(map)?;
-            }
-            None => {}
+        if let Some(e) = &mut self.guard {
+            e.resolve_resource(map)?;
         }
         for x in self.effects.iter_mut() {
             x.resolve_resource(map)?;
@@ -61,11 +88,8 @@ impl Event {
     }
 
     pub fn resolve_state(&mut self, map: &HashMap<String, StateId>) -> Result<(), RlError> {
-        match &mut self.guard {
-            Some(e) => {
-                e.resolve_state(map)?;
-            }
-            None => {}
+        if let Some(e) = &mut self.guard {
+            e.resolve_state(map)?;
         }
         for x in self.effects.iter_mut() {
             x.resolve_state(map)?;
@@ -94,11 +118,16 @@ impl Named<EventId> for Event {
 impl ToLang for Event {
     fn to_lang(&self, skillset: &Skillset) -> String {
         let mut s = String::new();
-        s.push_str(&format!("\t\t{} {{\n", self.name));
+        s.push_str(&format!("\t\t{}{} {{\n", self.attributes, self.name));
+        if let Some(description) = &self.description {
+            s.push_str(&format!("\t\t\tdescription \"{}\"\n", description));
+        }
+        if let Some(likelihood) = self.likelihood {
+            s.push_str(&format!("\t\t\t{}\n", likelihood));
+        }
         // guard
-        match &self.guard {
-            Some(guard) => s.push_str(&format!("\t\t\tguard {}\n", guard.to_lang(skillset))),
-            None => {}
+        if let Some(guard) = &self.guard {
+            s.push_str(&format!("\t\t\tguard {}\n", guard.to_lang(skillset)));
         }
         // Effects
         if !self.effects.is_empty() {
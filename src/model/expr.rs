@@ -15,6 +15,121 @@ pub enum Expr {
 }
 
 impl Expr {
+    pub fn and_all<I: IntoIterator<Item = Expr>>(exprs: I) -> Self {
+        let mut exprs = exprs.into_iter();
+        match exprs.next() {
+            None => Expr::True,
+            Some(first) => exprs.fold(first, |l, r| Expr::And(Box::new(l), Box::new(r))),
+        }
+    }
+
+    pub fn eval(&self, valuation: &Valuation) -> bool {
+        match self {
+            Expr::True => true,
+            Expr::False => false,
+            Expr::ResourceEq(resource, state) => {
+                valuation.get(resource.resolved()) == state.resolved()
+            }
+            Expr::ResourceNe(resource, state) => {
+                valuation.get(resource.resolved()) != state.resolved()
+            }
+            Expr::Not(e) => !e.eval(valuation),
+            Expr::And(l, r) => l.eval(valuation) && r.eval(valuation),
+            Expr::Or(l, r) => l.eval(valuation) || r.eval(valuation),
+            Expr::Implies(l, r) => !l.eval(valuation) || r.eval(valuation),
+        }
+    }
+
+    /// The resources the expression depends on.
+    pub fn resources(&self) -> Vec<ResourceId> {
+        let mut v = Vec::new();
+        self.add_resources(&mut v);
+        v
+    }
+
+    fn add_resources(&self, v: &mut Vec<ResourceId>) {
+        match self {
+            Expr::True | Expr::False => {}
+            Expr::ResourceEq(resource, _) | Expr::ResourceNe(resource, _) => {
+                if !v.contains(&resource.resolved()) {
+                    v.push(resource.resolved());
+                }
+            }
+            Expr::Not(e) => e.add_resources(v),
+            Expr::And(l, r) | Expr::Or(l, r) | Expr::Implies(l, r) => {
+                l.add_resources(v);
+                r.add_resources(v);
+            }
+        }
+    }
+
+    pub fn is_satisfiable(&self, skillset: &Skillset) -> bool {
+        Valuation::all_on(skillset, &self.resources())
+            .iter()
+            .any(|x| self.eval(x))
+    }
+
+    /// The expression on the state before the effects, that is equivalent to
+    /// this expression on the state after the effects.
+    pub fn before(&self, effects: &[Effect]) -> Self {
+        let target = |resource: &Reference<ResourceId>| {
+            effects
+                .iter()
+                .rev()
+                .find(|x| x.resource().resolved() == resource.resolved())
+                .map(|x| x.state().resolved())
+        };
+        match self {
+            Expr::True => Expr::True,
+            Expr::False => Expr::False,
+            Expr::ResourceEq(resource, state) => match target(resource) {
+                Some(id) if id == state.resolved() => Expr::True,
+                Some(_) => Expr::False,
+                None => self.clone(),
+            },
+            Expr::ResourceNe(resource, state) => match target(resource) {
+                Some(id) if id == state.resolved() => Expr::False,
+                Some(_) => Expr::True,
+                None => self.clone(),
+            },
+            Expr::Not(e) => Expr::Not(Box::new(e.before(effects))),
+            Expr::And(l, r) => Expr::And(Box::new(l.before(effects)), Box::new(r.before(effects))),
+            Expr::Or(l, r) => Expr::Or(Box::new(l.before(effects)), Box::new(r.before(effects))),
+            Expr::Implies(l, r) => {
+                Expr::Implies(Box::new(l.before(effects)), Box::new(r.before(effects)))
+            }
+        }
+    }
+
+    /// The expression without the true and false constants, unless it is a
+    /// constant itself.
+    pub fn simplified(&self) -> Self {
+        match self {
+            Expr::Not(e) => match e.simplified() {
+                Expr::True => Expr::False,
+                Expr::False => Expr::True,
+                e => Expr::Not(Box::new(e)),
+            },
+            Expr::And(l, r) => match (l.simplified(), r.simplified()) {
+                (Expr::False, _) | (_, Expr::False) => Expr::False,
+                (Expr::True, e) | (e, Expr::True) => e,
+                (l, r) => Expr::And(Box::new(l), Box::new(r)),
+            },
+            Expr::Or(l, r) => match (l.simplified(), r.simplified()) {
+                (Expr::True, _) | (_, Expr::True) => Expr::True,
+                (Expr::False, e) | (e, Expr::False) => e,
+                (l, r) => Expr::Or(Box::new(l), Box::new(r)),
+            },
+            Expr::Implies(l, r) => match (l.simplified(), r.simplified()) {
+                (Expr::False, _) | (_, Expr::True) => Expr::True,
+                (Expr::True, e) => e,
+                (e, Expr::False) => Expr::Not(Box::new(e)),
+                (l, r) => Expr::Implies(Box::new(l), Box::new(r)),
+            },
+            _ => self.clone(),
+        }
+    }
+
     pub fn resolve_resource(&mut self, map: &HashMap<String, ResourceId>) -> Result<(), RlError> {
         match self {
             Expr::True => Ok(()),
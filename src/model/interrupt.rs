@@ -4,24 +4,53 @@ use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 pub struct Interrupt {
+    interrupting: bool,
     effects: Vec<Effect>,
     postconditions: Vec<Postcondition>,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
 impl Interrupt {
     pub fn new(
+        interrupting: bool,
         postconditions: Vec<Postcondition>,
         effects: Vec<Effect>,
         position: Option<Position>,
     ) -> Self {
         Self {
+            interrupting,
             effects,
             postconditions,
+            description: None,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
+    }
+
+    /// Whether the interrupt is only requested, the skill running until its
+    /// execution acknowledges the request.
+    pub fn interrupting(&self) -> bool {
+        self.interrupting
+    }
+
     pub fn postconditions(&self) -> &Vec<Postcondition> {
         &self.postconditions
     }
@@ -59,7 +88,11 @@ impl Interrupt {
 
 impl ToLang for Interrupt {
     fn to_lang(&self, skillset: &Skillset) -> String {
-        let mut s = String::from("\t\t\tinterrupt {\n");
+        let mut s = format!("\t\t\tinterrupt {}{{\n", self.attributes);
+        if let Some(description) = &self.description {
+            s.push_str(&format!("\t\t\t\tdescription \"{}\"\n", description));
+        }
+        s.push_str(&format!("\t\t\t\tinterrupting {}\n", self.interrupting));
         // Postcondition
         if !self.postconditions.is_empty() {
             s.push_str("\t\t\t\tpostcondition {\n");
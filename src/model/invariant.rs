@@ -21,6 +21,7 @@ pub struct Invariant {
     name: String,
     guard: Expr,
     effects: Vec<Effect>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -38,10 +39,19 @@ impl Invariant {
             name,
             guard,
             effects,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
     pub fn guard(&self) -> &Expr {
         &self.guard
     }
@@ -89,7 +99,7 @@ impl Named<InvariantId> for Invariant {
 
 impl ToLang for Invariant {
     fn to_lang(&self, skillset: &Skillset) -> String {
-        let mut s = format!("{} {{\n", self.name);
+        let mut s = format!("{}{} {{\n", self.attributes, self.name);
         // guard
         s.push_str(&format!(
             "\t\t\t\t\tguard {}\n",
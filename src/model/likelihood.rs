@@ -0,0 +1,29 @@
+/// Quantitative annotation of an event or a terminating mode: a probability
+/// for the discrete time models, a rate for the continuous time ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Likelihood {
+    Probability(f64),
+    Rate(f64),
+}
+
+impl Likelihood {
+    pub fn value(&self) -> f64 {
+        match self {
+            Likelihood::Probability(x) => *x,
+            Likelihood::Rate(x) => *x,
+        }
+    }
+
+    pub fn is_rate(&self) -> bool {
+        matches!(self, Likelihood::Rate(_))
+    }
+}
+
+impl std::fmt::Display for Likelihood {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Likelihood::Probability(x) => write!(f, "probability {}", x),
+            Likelihood::Rate(x) => write!(f, "rate {}", x),
+        }
+    }
+}
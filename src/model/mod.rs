@@ -1,6 +1,9 @@
 pub mod reference;
 pub use reference::*;
 
+pub mod attribute;
+pub use attribute::*;
+
 // pub mod model;
 // pub use model::*;
 
@@ -20,6 +23,9 @@ pub use state::*;
 pub mod transition;
 pub use transition::*;
 
+pub mod constraint;
+pub use constraint::*;
+
 pub mod event;
 pub use event::*;
 
@@ -51,12 +57,21 @@ pub use terminate::*;
 pub mod effect;
 pub use effect::*;
 
+pub mod likelihood;
+pub use likelihood::*;
+
 pub mod variable;
 pub use variable::*;
 
 pub mod expr;
 pub use expr::*;
 
+pub mod action;
+pub use action::*;
+
+pub mod valuation;
+pub use valuation::*;
+
 use crate::parser::{Position, RlError};
 
 pub trait ToLang {
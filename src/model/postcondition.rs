@@ -15,6 +15,7 @@ pub struct Postcondition {
     id: PostconditionId,
     name: String,
     expr: Expr,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -26,10 +27,19 @@ impl Postcondition {
             id,
             name,
             expr,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
     pub fn expr(&self) -> &Expr {
         &self.expr
     }
@@ -65,7 +75,16 @@ impl Named<PostconditionId> for Postcondition {
 
 impl ToLang for Postcondition {
     fn to_lang(&self, skillset: &Skillset) -> String {
-        format!("{}: {}", self.name, self.expr.to_lang(skillset))
+        if self.name.is_empty() {
+            format!("{}{}", self.attributes, self.expr.to_lang(skillset))
+        } else {
+            format!(
+                "{}{}: {}",
+                self.attributes,
+                self.name,
+                self.expr.to_lang(skillset)
+            )
+        }
     }
 }
 
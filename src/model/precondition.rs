@@ -20,6 +20,7 @@ pub struct Precondition {
     id: PreconditionId,
     name: String,
     expr: Expr,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -31,10 +32,19 @@ impl Precondition {
             id,
             name,
             expr,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
     pub fn expr(&self) -> &Expr {
         &self.expr
     }
@@ -70,7 +80,12 @@ impl Named<PreconditionId> for Precondition {
 
 impl ToLang for Precondition {
     fn to_lang(&self, skillset: &Skillset) -> String {
-        format!("{}: {}", self.name, self.expr.to_lang(skillset))
+        format!(
+            "{}{}: {}",
+            self.attributes,
+            self.name,
+            self.expr.to_lang(skillset)
+        )
     }
 }
 
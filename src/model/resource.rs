@@ -17,6 +17,8 @@ pub struct Resource {
     states: Vec<State>,
     initial: Reference<StateId>,
     transitions: Transitions,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -30,10 +32,38 @@ impl Resource {
             states: Vec::new(),
             initial: Reference::Unresolved("?".into(), None),
             transitions: Transitions::All,
+            description: None,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
+    }
+
+    /// Changed only by the environment through the events: `@extern`.
+    pub fn is_extern(&self) -> bool {
+        self.attributes.has("extern")
+    }
+
+    /// Changed only by the skills: `@internal`.
+    pub fn is_internal(&self) -> bool {
+        self.attributes.has("internal")
+    }
+
     //---------- State ----------
 
     pub fn states(&self) -> &Vec<State> {
@@ -73,12 +103,7 @@ impl Resource {
     }
 
     pub fn get_state_from_name(&self, name: &str) -> Option<&State> {
-        for x in self.states.iter() {
-            if x.name() == name {
-                return Some(x);
-            }
-        }
-        None
+        self.states.iter().find(|x| x.name() == name)
     }
 
     //---------- Transition ----------
@@ -100,7 +125,7 @@ impl Resource {
                     for dst in self.states.iter() {
                         let r_dst = Reference::Resolved(dst.id());
                         if src.id() != dst.id() {
-                            transitions.push(Transition::new(r_src.clone(), r_dst.clone()));
+                            transitions.push(Transition::new(r_src.clone(), r_dst.clone(), None));
                         }
                     }
                 }
@@ -110,6 +135,15 @@ impl Resource {
         }
     }
 
+    pub fn has_transition(&self, src: StateId, dst: StateId) -> bool {
+        match self.transitions() {
+            Transitions::All => src != dst,
+            Transitions::List(transitions) => transitions
+                .iter()
+                .any(|x| x.src().resolved() == src && x.dst().resolved() == dst),
+        }
+    }
+
     //---------- Duplicate ----------
 
     pub fn names(&self) -> Vec<(String, Option<Position>)> {
@@ -181,11 +215,17 @@ impl GetFromId<StateId, State> for Resource {
 impl ToLang for Resource {
     fn to_lang(&self, skillset: &Skillset) -> String {
         let mut s = String::new();
-        s.push_str(&format!("\t\t{} {{\n", self.name));
+        s.push_str(&format!("\t\t{}{} {{\n", self.attributes, self.name));
+        if let Some(description) = &self.description {
+            s.push_str(&format!("\t\t\tdescription \"{}\"\n", description));
+        }
         // state
         s.push_str("\t\t\tstate {");
         for x in self.states.iter() {
-            s.push_str(&format!(" {}", x));
+            s.push_str(&format!(" {}{}", x.attributes(), x));
+            if let Some(description) = x.description() {
+                s.push_str(&format!(" description \"{}\"", description));
+            }
         }
         s.push_str(" }\n");
         // initial
@@ -23,6 +23,8 @@ pub struct Skill {
     interrupt: Option<Interrupt>,
     successes: Vec<Success>,
     failures: Vec<Failure>,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -42,10 +44,28 @@ impl Skill {
             interrupt: None,
             successes: Vec::new(),
             failures: Vec::new(),
+            description: None,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
+    }
+
     //---------- Input ----------
 
     pub fn inputs(&self) -> &Vec<Variable> {
@@ -162,6 +182,10 @@ impl Skill {
         }
     }
 
+    pub fn get_success_from_name(&self, name: &str) -> Option<&Success> {
+        self.successes.iter().find(|x| x.name() == name)
+    }
+
     //---------- Failure ----------
 
     pub fn failures(&self) -> &Vec<Failure> {
@@ -184,6 +208,10 @@ impl Skill {
         }
     }
 
+    pub fn get_failure_from_name(&self, name: &str) -> Option<&Failure> {
+        self.failures.iter().find(|x| x.name() == name)
+    }
+
     //---------- Duplicate ----------
 
     pub fn input_naming(&self) -> Vec<Naming> {
@@ -219,7 +247,7 @@ impl Skill {
             types
                 .clone()
                 .into_iter()
-                .chain(self.input_naming().into_iter())
+                .chain(self.input_naming())
                 .collect(),
         )?;
         // Output
@@ -227,7 +255,7 @@ impl Skill {
             types
                 .clone()
                 .into_iter()
-                .chain(self.output_naming().into_iter())
+                .chain(self.output_naming())
                 .collect(),
         )?;
         // Precondition
@@ -235,7 +263,7 @@ impl Skill {
             types
                 .clone()
                 .into_iter()
-                .chain(self.precondition_naming().into_iter())
+                .chain(self.precondition_naming())
                 .collect(),
         )?;
         // Invariant
@@ -243,7 +271,7 @@ impl Skill {
             types
                 .clone()
                 .into_iter()
-                .chain(self.invariant_naming().into_iter())
+                .chain(self.invariant_naming())
                 .collect(),
         )?;
         // Success
@@ -251,7 +279,7 @@ impl Skill {
             types
                 .clone()
                 .into_iter()
-                .chain(self.success_naming().into_iter())
+                .chain(self.success_naming())
                 .collect(),
         )?;
         // Failure
@@ -259,7 +287,7 @@ impl Skill {
             types
                 .clone()
                 .into_iter()
-                .chain(self.failure_naming().into_iter())
+                .chain(self.failure_naming())
                 .collect(),
         )?;
 
@@ -396,7 +424,10 @@ impl GetFromId<FailureId, Failure> for Skill {
 impl ToLang for Skill {
     fn to_lang(&self, skillset: &Skillset) -> String {
         let mut s = String::new();
-        s.push_str(&format!("\t\t{} {{\n", self.name));
+        s.push_str(&format!("\t\t{}{} {{\n", self.attributes, self.name));
+        if let Some(description) = &self.description {
+            s.push_str(&format!("\t\t\tdescription \"{}\"\n", description));
+        }
         // Input
         if !self.inputs.is_empty() {
             s.push_str("\t\t\tinput {\n");
@@ -8,8 +8,10 @@ pub struct Skillset {
     types: Vec<RlType>,
     data: Vec<Data>,
     resources: Vec<Resource>,
+    constraints: Vec<Constraint>,
     events: Vec<Event>,
     skills: Vec<Skill>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -21,12 +23,22 @@ impl Skillset {
             types: Default::default(),
             data: Default::default(),
             resources: Default::default(),
+            constraints: Default::default(),
             events: Default::default(),
             skills: Default::default(),
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -71,6 +83,10 @@ impl Skillset {
         self.data.get(id.index())
     }
 
+    pub fn get_data_from_name(&self, name: &str) -> Option<&Data> {
+        self.data.iter().find(|x| x.name() == name)
+    }
+
     pub fn add_data(&mut self, mut data: Data) -> DataId {
         let id = DataId(self.data.len());
         data.set_id(id);
@@ -84,6 +100,11 @@ impl Skillset {
         &self.resources
     }
 
+    /// The resources fed by the environment.
+    pub fn extern_resources(&self) -> Vec<&Resource> {
+        self.resources.iter().filter(|x| x.is_extern()).collect()
+    }
+
     pub fn get_resource(&self, id: ResourceId) -> Option<&Resource> {
         self.resources.get(id.index())
     }
@@ -120,6 +141,23 @@ impl Skillset {
         map
     }
 
+    //---------- Constraint ----------
+
+    pub fn constraints(&self) -> &Vec<Constraint> {
+        &self.constraints
+    }
+
+    pub fn get_constraint(&self, id: ConstraintId) -> Option<&Constraint> {
+        self.constraints.get(id.index())
+    }
+
+    pub fn add_constraint(&mut self, mut constraint: Constraint) -> ConstraintId {
+        let id = ConstraintId(self.constraints.len());
+        constraint.set_id(id);
+        self.constraints.push(constraint);
+        id
+    }
+
     //---------- Event ----------
 
     pub fn events(&self) -> &Vec<Event> {
@@ -130,6 +168,10 @@ impl Skillset {
         self.events.get(id.index())
     }
 
+    pub fn get_event_from_name(&self, name: &str) -> Option<&Event> {
+        self.events.iter().find(|x| x.name() == name)
+    }
+
     pub fn add_event(&mut self, mut event: Event) -> EventId {
         let id = EventId(self.events.len());
         event.set_id(id);
@@ -147,6 +189,10 @@ impl Skillset {
         self.skills.get(id.index())
     }
 
+    pub fn get_skill_from_name(&self, name: &str) -> Option<&Skill> {
+        self.skills.iter().find(|x| x.name() == name)
+    }
+
     pub fn add_skill(&mut self, mut skill: Skill) -> SkillId {
         let id = SkillId(self.skills.len());
         skill.set_id(id);
@@ -154,6 +200,29 @@ impl Skillset {
         id
     }
 
+    //---------- Action ----------
+
+    pub fn actions(&self) -> Vec<Action> {
+        let mut v = Vec::new();
+        for x in self.events.iter() {
+            v.push(Action::Event(x.id()));
+        }
+        for x in self.skills.iter() {
+            v.push(Action::Start(x.id()));
+            for y in x.invariants().iter() {
+                v.push(Action::Invariant(y.id()));
+            }
+            v.push(Action::Interrupt(x.id()));
+            for y in x.successes().iter() {
+                v.push(Action::Success(y.id()));
+            }
+            for y in x.failures().iter() {
+                v.push(Action::Failure(y.id()));
+            }
+        }
+        v
+    }
+
     //---------- Duplicate ----------
 
     pub fn type_naming(&self) -> Vec<Naming> {
@@ -171,6 +240,9 @@ impl Skillset {
         }
         v
     }
+    pub fn constraint_naming(&self) -> Vec<Naming> {
+        self.constraints.iter().map(|x| x.naming()).collect()
+    }
     pub fn event_naming(&self) -> Vec<Naming> {
         self.events.iter().map(|x| x.naming()).collect()
     }
@@ -186,7 +258,7 @@ impl Skillset {
             types
                 .clone()
                 .into_iter()
-                .chain(self.data_naming().into_iter())
+                .chain(self.data_naming())
                 .collect(),
         )?;
         // Resource
@@ -194,15 +266,17 @@ impl Skillset {
             types
                 .clone()
                 .into_iter()
-                .chain(self.resource_naming().into_iter())
+                .chain(self.resource_naming())
                 .collect(),
         )?;
+        // Constraint
+        check_duplicate(self.constraint_naming())?;
         // Event
         check_duplicate(
             types
                 .clone()
                 .into_iter()
-                .chain(self.event_naming().into_iter())
+                .chain(self.event_naming())
                 .collect(),
         )?;
         // Skill
@@ -210,7 +284,7 @@ impl Skillset {
             types
                 .clone()
                 .into_iter()
-                .chain(self.skill_naming().into_iter())
+                .chain(self.skill_naming())
                 .collect(),
         )?;
 
@@ -245,6 +319,10 @@ impl Skillset {
 
     pub fn resolve_resource(&mut self) -> Result<(), RlError> {
         let map = self.resource_map();
+        // Constraint
+        for x in self.constraints.iter_mut() {
+            x.resolve_resource(&map)?;
+        }
         // Event
         for x in self.events.iter_mut() {
             x.resolve_resource(&map)?;
@@ -261,8 +339,12 @@ impl Skillset {
         for x in self.resources.iter_mut() {
             x.resolve_state()?;
         }
-        // Event
+        // Constraint
         let map = self.state_map();
+        for x in self.constraints.iter_mut() {
+            x.resolve_state(&map)?;
+        }
+        // Event
         for x in self.events.iter_mut() {
             x.resolve_state(&map)?;
         }
@@ -298,6 +380,11 @@ impl GetFromId<StateId, State> for Skillset {
         self.get_state(id)
     }
 }
+impl GetFromId<ConstraintId, Constraint> for Skillset {
+    fn get(&self, id: ConstraintId) -> Option<&Constraint> {
+        self.get_constraint(id)
+    }
+}
 impl GetFromId<EventId, Event> for Skillset {
     fn get(&self, id: EventId) -> Option<&Event> {
         self.get_event(id)
@@ -337,7 +424,7 @@ impl GetFromId<FailureId, Failure> for Skillset {
 
 impl std::fmt::Display for Skillset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "skillset {}", self.name)?;
+        write!(f, "skillset {}{}", self.attributes, self.name)?;
         // Types
         if let Some((first, others)) = self.types.split_first() {
             write!(f, "<{}", first.name())?;
@@ -346,41 +433,49 @@ impl std::fmt::Display for Skillset {
             }
             write!(f, ">")?;
         }
-        write!(f, " {{\n")?;
+        writeln!(f, " {{")?;
         // Data
         if !self.data.is_empty() {
-            write!(f, "\tdata {{\n")?;
+            writeln!(f, "\tdata {{")?;
             for x in self.data.iter() {
                 write!(f, "\t\t{}", &x.to_lang(self))?;
             }
-            write!(f, "\t}}\n")?;
+            writeln!(f, "\t}}")?;
         }
         // Resource
         if !self.resources.is_empty() {
-            write!(f, "\tresource {{\n")?;
+            writeln!(f, "\tresource {{")?;
             for x in self.resources.iter() {
                 write!(f, "{}", x.to_lang(self))?;
             }
-            write!(f, "\t}}\n")?;
+            writeln!(f, "\t}}")?;
+        }
+        // Constraint
+        if !self.constraints.is_empty() {
+            writeln!(f, "\tconstraint {{")?;
+            for x in self.constraints.iter() {
+                write!(f, "\t\t{}", x.to_lang(self))?;
+            }
+            writeln!(f, "\t}}")?;
         }
         // Event
         if !self.events.is_empty() {
-            write!(f, "\tevent {{\n")?;
+            writeln!(f, "\tevent {{")?;
             for x in self.events.iter() {
                 write!(f, "{}", x.to_lang(self))?;
             }
-            write!(f, "\t}}\n")?;
+            writeln!(f, "\t}}")?;
         }
         // Skill
         if !self.skills.is_empty() {
-            write!(f, "\tskill {{\n")?;
+            writeln!(f, "\tskill {{")?;
             for x in self.skills.iter() {
                 write!(f, "{}", x.to_lang(self))?;
             }
-            write!(f, "\t}}\n")?;
+            writeln!(f, "\t}}")?;
         }
         //
-        write!(f, "}}\n")?;
+        writeln!(f, "}}")?;
         //
         Ok(())
     }
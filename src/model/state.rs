@@ -18,6 +18,8 @@ impl StateId {
 pub struct State {
     id: StateId,
     name: String,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -25,7 +27,29 @@ impl State {
     pub fn new<S: Into<String>>(name: S, position: Option<Position>) -> Self {
         let id = StateId::default();
         let name = name.into();
-        Self { id, name, position }
+        Self {
+            id,
+            name,
+            description: None,
+            attributes: Attributes::default(),
+            position,
+        }
+    }
+
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
     }
 }
 
@@ -41,6 +41,9 @@ pub struct Terminate<I: TerminateId> {
     name: String,
     postconditions: Vec<Postcondition>,
     effects: Vec<Effect>,
+    likelihood: Option<Likelihood>,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -58,10 +61,29 @@ impl<I: TerminateId> Terminate<I> {
             name,
             effects,
             postconditions,
+            likelihood: None,
+            description: None,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
+    }
+
     pub fn postconditions(&self) -> &Vec<Postcondition> {
         &self.postconditions
     }
@@ -70,6 +92,14 @@ impl<I: TerminateId> Terminate<I> {
         &self.effects
     }
 
+    pub fn likelihood(&self) -> Option<Likelihood> {
+        self.likelihood
+    }
+
+    pub fn set_likelihood(&mut self, likelihood: Likelihood) {
+        self.likelihood = Some(likelihood);
+    }
+
     //---------- Resolve ----------
 
     pub fn resolve_resource(&mut self, map: &HashMap<String, ResourceId>) -> Result<(), RlError> {
@@ -123,10 +153,16 @@ impl Named<FailureId> for Failure {
 }
 impl<I: TerminateId> ToLang for Terminate<I> {
     fn to_lang(&self, skillset: &Skillset) -> String {
-        let mut s = format!("{} {{\n", self.name);
+        let mut s = format!("{}{} {{\n", self.attributes, self.name);
+        if let Some(description) = &self.description {
+            s.push_str(&format!("\t\t\t\t\tdescription \"{}\"\n", description));
+        }
+        if let Some(likelihood) = self.likelihood {
+            s.push_str(&format!("\t\t\t\t\t{}\n", likelihood));
+        }
         // Postcondition
         if !self.postconditions.is_empty() {
-            s.push_str("\t\t\t\t\teffect {\n");
+            s.push_str("\t\t\t\t\tpostcondition {\n");
             for x in self.postconditions.iter() {
                 s.push_str(&format!("\t\t\t\t\t\t{}\n", x.to_lang(skillset)))
             }
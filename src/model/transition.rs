@@ -1,4 +1,4 @@
-use crate::parser::RlError;
+use crate::parser::{Position, RlError};
 use std::collections::HashMap;
 
 use super::*;
@@ -7,11 +7,30 @@ use super::*;
 pub struct Transition {
     src: Reference<StateId>,
     dst: Reference<StateId>,
+    attributes: Attributes,
+    position: Option<Position>,
 }
 
 impl Transition {
-    pub fn new(src: Reference<StateId>, dst: Reference<StateId>) -> Self {
-        Self { src, dst }
+    pub fn new(
+        src: Reference<StateId>,
+        dst: Reference<StateId>,
+        position: Option<Position>,
+    ) -> Self {
+        Self {
+            src,
+            dst,
+            attributes: Attributes::default(),
+            position,
+        }
+    }
+
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
     }
 
     pub fn src(&self) -> &Reference<StateId> {
@@ -21,6 +40,10 @@ impl Transition {
     pub fn dst(&self) -> &Reference<StateId> {
         &self.dst
     }
+
+    pub fn position(&self) -> Option<Position> {
+        self.position.clone()
+    }
 }
 
 impl Transition {
@@ -64,7 +87,8 @@ impl Transition {
 impl ToLang for Transition {
     fn to_lang(&self, skillset: &Skillset) -> String {
         format!(
-            "{} -> {}",
+            "{}{} -> {}",
+            self.attributes,
             self.src.to_lang(skillset),
             self.dst.to_lang(skillset)
         )
@@ -96,7 +120,7 @@ impl Transitions {
 impl ToLang for Transitions {
     fn to_lang(&self, skillset: &Skillset) -> String {
         match self {
-            Transitions::All => format!("\t\t\ttransition all\n"),
+            Transitions::All => "\t\t\ttransition all\n".to_string(),
             Transitions::List(l) => {
                 let mut s = String::from("\t\t\ttransition {\n");
                 for x in l {
@@ -0,0 +1,96 @@
+use super::*;
+
+/// The current state of every resource of a skillset, indexed by resource.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Valuation(Vec<StateId>);
+
+impl Valuation {
+    pub fn new(states: Vec<StateId>) -> Self {
+        Self(states)
+    }
+
+    pub fn initial(skillset: &Skillset) -> Self {
+        Self(skillset.resources().iter().map(|x| x.initial()).collect())
+    }
+
+    pub fn states(&self) -> &Vec<StateId> {
+        &self.0
+    }
+
+    pub fn get(&self, id: ResourceId) -> StateId {
+        self.0[id.index()]
+    }
+
+    pub fn set(&mut self, state: StateId) {
+        self.0[state.resource().index()] = state;
+    }
+
+    pub fn apply(&mut self, effects: &[Effect]) {
+        for x in effects.iter() {
+            self.set(x.state().resolved());
+        }
+    }
+
+    pub fn applied(&self, effects: &[Effect]) -> Self {
+        let mut valuation = self.clone();
+        valuation.apply(effects);
+        valuation
+    }
+
+    /// Every valuation of the skillset resources.
+    pub fn all(skillset: &Skillset) -> Vec<Self> {
+        let mut v = vec![Self(Vec::new())];
+        for resource in skillset.resources() {
+            let mut next = Vec::new();
+            for x in v.iter() {
+                for state in resource.states() {
+                    let mut states = x.0.clone();
+                    states.push(state.id());
+                    next.push(Self(states));
+                }
+            }
+            v = next;
+        }
+        v
+    }
+
+    /// Every valuation of the given resources, the other ones being in their
+    /// initial state.
+    pub fn all_on(skillset: &Skillset, resources: &[ResourceId]) -> Vec<Self> {
+        let mut v = vec![Self::initial(skillset)];
+        for id in resources {
+            let resource = skillset.get(*id).unwrap();
+            let mut next = Vec::new();
+            for x in v.iter() {
+                for state in resource.states() {
+                    let mut valuation = x.clone();
+                    valuation.set(state.id());
+                    next.push(valuation);
+                }
+            }
+            v = next;
+        }
+        v
+    }
+}
+
+impl ToLang for Valuation {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        let v: Vec<String> = self
+            .0
+            .iter()
+            .map(|x| {
+                format!(
+                    "{} == {}",
+                    skillset.get(x.resource()).unwrap(),
+                    skillset.get(*x).unwrap()
+                )
+            })
+            .collect();
+        if v.is_empty() {
+            "true".into()
+        } else {
+            v.join(" and ")
+        }
+    }
+}
@@ -6,6 +6,8 @@ use std::collections::HashMap;
 pub struct Variable {
     name: String,
     rl_type: Reference<TypeId>,
+    description: Option<String>,
+    attributes: Attributes,
     position: Option<Position>,
 }
 
@@ -19,10 +21,28 @@ impl Variable {
         Self {
             name,
             rl_type,
+            description: None,
+            attributes: Attributes::default(),
             position,
         }
     }
 
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description<S: Into<String>>(&mut self, description: S) {
+        self.description = Some(description.into());
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -60,7 +80,21 @@ impl Variable {
 
 impl ToLang for Variable {
     fn to_lang(&self, skillset: &Skillset) -> String {
-        format!("{}: {}", self.name, self.rl_type.to_lang(skillset))
+        match &self.description {
+            Some(description) => format!(
+                "{}{}: {} description \"{}\"",
+                self.attributes,
+                self.name,
+                self.rl_type.to_lang(skillset),
+                description
+            ),
+            None => format!(
+                "{}{}: {}",
+                self.attributes,
+                self.name,
+                self.rl_type.to_lang(skillset)
+            ),
+        }
     }
 }
 
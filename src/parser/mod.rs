@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod parser;
 pub use parser::*;
 
@@ -7,7 +8,7 @@ pub use error::*;
 pub mod position;
 pub use position::*;
 
-use crate::model::Skillset;
+use crate::model::{Expr, Skillset};
 use line_col::LineColLookup;
 
 #[derive(Debug, Clone)]
@@ -29,3 +30,13 @@ pub fn parse_file(file: &str) -> Result<Skillset, RlError> {
     parser.parse()?;
     Ok(parser.skillset)
 }
+
+pub fn parse_expr(skillset: &Skillset, input: &str) -> Result<Expr, RlError> {
+    let file = "<expr>";
+    let mut parser = Parser::new(file);
+    parser.next();
+    let mut expr = parser.parse_expr(input)?;
+    expr.resolve_resource(&skillset.resource_map())?;
+    expr.resolve_state(&skillset.state_map())?;
+    Ok(expr)
+}
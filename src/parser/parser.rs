@@ -1,10 +1,15 @@
-use crate::model::Skillset;
+use crate::model::{Expr, Skillset};
 use crate::parser::error::*;
 use line_col::LineColLookup;
 use std::fs;
 
-lalrpop_mod!(grammar, "/parser/grammar.rs");
+lalrpop_mod!(
+    #[allow(clippy::all)]
+    grammar,
+    "/parser/grammar.rs"
+);
 
+#[derive(Default)]
 pub struct Parser {
     current: Option<String>,
     todo: Vec<String>,
@@ -12,17 +17,6 @@ pub struct Parser {
     pub skillset: Skillset,
 }
 
-impl Default for Parser {
-    fn default() -> Self {
-        Self {
-            current: Default::default(),
-            todo: Default::default(),
-            done: Default::default(),
-            skillset: Default::default(),
-        }
-    }
-}
-
 impl Parser {
     pub fn new<S: Into<String>>(file: S) -> Self {
         Self {
@@ -56,8 +50,8 @@ impl Parser {
         if let Some(f) = &self.current {
             v.push(f.clone());
         }
-        v.extend(self.todo.clone().into_iter());
-        v.extend(self.done.clone().into_iter());
+        v.extend(self.todo.clone());
+        v.extend(self.done.clone());
         v
     }
 
@@ -71,6 +65,7 @@ impl Parser {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<String> {
         if let Some(file) = &self.current {
             self.done.push(file.clone());
@@ -107,4 +102,12 @@ impl Parser {
             }
         }
     }
+
+    pub fn parse_expr(&mut self, input: &str) -> Result<Expr, RlError> {
+        let lookup = LineColLookup::new(input);
+        match grammar::ExprParser::new().parse(&lookup, self, input) {
+            Ok(expr) => Ok(expr),
+            Err(e) => Err(RlError::new_parse(self.file(), &lookup, e)),
+        }
+    }
 }
@@ -0,0 +1,231 @@
+use crate::model::*;
+use std::collections::{HashMap, VecDeque};
+
+// Skills are executed one after the other and events are not considered.
+// A skill can be executed when its preconditions hold and its invariants
+// hold after its start effects. It then terminates in any of its success or
+// failure modes: the skill is assumed to bring the resources of the
+// postconditions of the mode in any states where they hold (see
+// Action::terminations), the invariants still holding, then the effects of
+// the mode are applied.
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Outcome {
+    Success(SuccessId),
+    Failure(FailureId),
+}
+
+impl Outcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Outcome::Success(_))
+    }
+}
+
+impl ToLang for Outcome {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        match self {
+            Outcome::Success(id) => format!("success {}", skillset.get(*id).unwrap()),
+            Outcome::Failure(id) => format!("failure {}", skillset.get(*id).unwrap()),
+        }
+    }
+}
+
+/// The possible outcomes of a skill executed from a valuation, with the
+/// resulting valuations (several for a mode whose postconditions allow
+/// several states).
+pub fn outcomes(
+    skillset: &Skillset,
+    id: SkillId,
+    valuation: &Valuation,
+) -> Vec<(Outcome, Valuation)> {
+    let skill = skillset.get(id).unwrap();
+    let mut v = Vec::new();
+    if !skill
+        .preconditions()
+        .iter()
+        .all(|x| x.expr().eval(valuation))
+    {
+        return v;
+    }
+    let running = valuation.applied(skill.start());
+    if !skill.invariants().iter().all(|x| x.guard().eval(&running)) {
+        return v;
+    }
+    for x in skill.successes() {
+        for next in terminate(skillset, &Action::Success(x.id()), &running) {
+            v.push((Outcome::Success(x.id()), next));
+        }
+    }
+    for x in skill.failures() {
+        for next in terminate(skillset, &Action::Failure(x.id()), &running) {
+            v.push((Outcome::Failure(x.id()), next));
+        }
+    }
+    v
+}
+
+/// The valuations after the termination of a running skill by the action.
+fn terminate(skillset: &Skillset, action: &Action, running: &Valuation) -> Vec<Valuation> {
+    let guard = action.guard(skillset);
+    action
+        .terminations(skillset)
+        .iter()
+        .map(|x| running.applied(x))
+        .filter(|x| guard.eval(x))
+        .map(|x| x.applied(action.effects(skillset)))
+        .collect()
+}
+
+//------------------------- Plan -------------------------
+
+/// A shortest sequence of skills leading from the initial valuation to a
+/// valuation satisfying the goal, assuming every skill succeeds (in any of
+/// its success modes).
+pub fn plan(skillset: &Skillset, initial: &Valuation, goal: &Expr) -> Option<Vec<SkillId>> {
+    let mut parents: HashMap<Valuation, Option<(Valuation, SkillId)>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    parents.insert(initial.clone(), None);
+    queue.push_back(initial.clone());
+    while let Some(valuation) = queue.pop_front() {
+        if goal.eval(&valuation) {
+            let mut skills = Vec::new();
+            let mut current = valuation;
+            while let Some((previous, skill)) = parents.get(&current).unwrap() {
+                skills.push(*skill);
+                current = previous.clone();
+            }
+            skills.reverse();
+            return Some(skills);
+        }
+        for skill in skillset.skills() {
+            for (outcome, next) in outcomes(skillset, skill.id(), &valuation) {
+                if outcome.is_success() && !parents.contains_key(&next) {
+                    parents.insert(next.clone(), Some((valuation.clone(), skill.id())));
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    None
+}
+
+//------------------------- Contingent Plan -------------------------
+
+#[derive(Clone, Debug)]
+pub enum PlanTree {
+    Goal,
+    Skill {
+        skill: SkillId,
+        /// The plan to follow after each outcome, None if the goal cannot be
+        /// reached anymore.
+        branches: Vec<(Outcome, Option<PlanTree>)>,
+    },
+}
+
+impl PlanTree {
+    fn to_lang_indent(&self, skillset: &Skillset, indent: usize) -> String {
+        let tabs = "\t".repeat(indent);
+        match self {
+            PlanTree::Goal => format!("{}goal\n", tabs),
+            PlanTree::Skill { skill, branches } => {
+                let mut s = format!("{}{}\n", tabs, skillset.get(*skill).unwrap());
+                for (outcome, plan) in branches.iter() {
+                    s.push_str(&format!("{}\t{}:\n", tabs, outcome.to_lang(skillset)));
+                    match plan {
+                        Some(plan) => s.push_str(&plan.to_lang_indent(skillset, indent + 2)),
+                        None => s.push_str(&format!("{}\t\tno plan\n", tabs)),
+                    }
+                }
+                s
+            }
+        }
+    }
+}
+
+impl ToLang for PlanTree {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        self.to_lang_indent(skillset, 0)
+    }
+}
+
+/// A plan of at most depth skills on each branch, that reaches the goal
+/// whatever the success mode of the skills, and that recovers from failures
+/// when possible. Plans recovering from all failures are preferred, then the
+/// ones with the fewest skills until the goal when all skills succeed.
+pub fn contingent_plan(
+    skillset: &Skillset,
+    initial: &Valuation,
+    goal: &Expr,
+    depth: usize,
+) -> Option<PlanTree> {
+    let mut memory = HashMap::new();
+    contingent(skillset, initial, goal, depth, &mut memory).map(|(plan, _, _)| plan)
+}
+
+type Contingent = Option<(PlanTree, bool, usize)>;
+
+/// The plan, whether it recovers from all failures, and its length when all
+/// skills succeed.
+fn contingent(
+    skillset: &Skillset,
+    valuation: &Valuation,
+    goal: &Expr,
+    depth: usize,
+    memory: &mut HashMap<(Valuation, usize), Contingent>,
+) -> Contingent {
+    if goal.eval(valuation) {
+        return Some((PlanTree::Goal, true, 0));
+    }
+    if depth == 0 {
+        return None;
+    }
+    if let Some(result) = memory.get(&(valuation.clone(), depth)) {
+        return result.clone();
+    }
+    let mut candidate: Contingent = None;
+    for skill in skillset.skills() {
+        let outcomes = outcomes(skillset, skill.id(), valuation);
+        if !outcomes.iter().any(|(x, _)| x.is_success()) {
+            continue;
+        }
+        let mut branches = Vec::new();
+        let mut complete = true;
+        let mut recovered = true;
+        let mut length = 0;
+        for (outcome, next) in outcomes {
+            match contingent(skillset, &next, goal, depth - 1, memory) {
+                Some((plan, r, l)) => {
+                    recovered &= r;
+                    if outcome.is_success() {
+                        length = length.max(l + 1);
+                    }
+                    branches.push((outcome, Some(plan)));
+                }
+                None if outcome.is_success() => {
+                    complete = false;
+                    break;
+                }
+                None => {
+                    recovered = false;
+                    branches.push((outcome, None));
+                }
+            }
+        }
+        if !complete {
+            continue;
+        }
+        let better = match &candidate {
+            None => true,
+            Some((_, r, l)) => (recovered, std::cmp::Reverse(length)) > (*r, std::cmp::Reverse(*l)),
+        };
+        if better {
+            let tree = PlanTree::Skill {
+                skill: skill.id(),
+                branches,
+            };
+            candidate = Some((tree, recovered, length));
+        }
+    }
+    memory.insert((valuation.clone(), depth), candidate.clone());
+    candidate
+}
@@ -0,0 +1,321 @@
+use super::*;
+use crate::analysis::Configuration;
+use crate::model::*;
+
+// The engine follows the semantics of the analyses: a request is the action
+// of the same name, refused when its condition does not hold. The analyses
+// only terminate a skill where the postconditions of the mode hold; a skill
+// that has terminated elsewhere cannot be kept running, so its termination
+// is still performed, and reported as outside of the model. After every
+// accepted action, the running skills whose invariant is violated are
+// stopped one after the other (applying the invariant effects), then the
+// constraints of the skillset are checked. The interrupt of an interrupting
+// skill is only requested: the skill keeps running until its execution
+// acknowledges the request, which performs the interrupt. The request does
+// not change the resources, so the analyses, that interleave the interrupt
+// with the other actions, are unchanged.
+
+/// What happened while processing a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Report {
+    Action(Action),
+    /// The postconditions of the termination did not hold when the skill
+    /// terminated, a termination the analyses do not consider.
+    Postcondition(Action),
+    /// The constraint does not hold after the request.
+    Violation(ConstraintId),
+    /// The interrupt of the interrupting skill has been requested, the skill
+    /// running until the request is acknowledged.
+    Interrupting(SkillId),
+}
+
+impl ToLang for Report {
+    fn to_lang(&self, skillset: &Skillset) -> String {
+        match self {
+            Report::Action(action) => action.to_lang(skillset),
+            Report::Postcondition(action) => {
+                format!(
+                    "postcondition of {} does not hold",
+                    action.to_lang(skillset)
+                )
+            }
+            Report::Violation(id) => format!("violation {}", skillset.get(*id).unwrap()),
+            Report::Interrupting(id) => format!("interrupting {}", skillset.get(*id).unwrap()),
+        }
+    }
+}
+
+/// A resolved skillset running as a live state machine. The handlers are
+/// boxed as H, `dyn SkillHandler + Send` for an engine shared between threads.
+pub struct Engine<H: ?Sized = dyn SkillHandler> {
+    skillset: Skillset,
+    configuration: Configuration,
+    handlers: Vec<Option<Box<H>>>,
+    interrupting: Vec<bool>,
+}
+
+impl Engine {
+    pub fn new(skillset: Skillset) -> Self {
+        Self::with_handlers(skillset)
+    }
+
+    pub fn set_handler<T: SkillHandler + 'static>(
+        &mut self,
+        skill: &str,
+        handler: T,
+    ) -> Result<(), RuntimeError> {
+        self.set_boxed_handler(skill, Box::new(handler))
+    }
+}
+
+impl<H: SkillHandler + ?Sized> Engine<H> {
+    /// An engine without handlers, whose handlers are boxed as H.
+    pub fn with_handlers(skillset: Skillset) -> Self {
+        let configuration = Configuration::initial(&skillset);
+        let handlers = skillset.skills().iter().map(|_| None).collect();
+        let interrupting = skillset.skills().iter().map(|_| false).collect();
+        Self {
+            skillset,
+            configuration,
+            handlers,
+            interrupting,
+        }
+    }
+
+    pub fn skillset(&self) -> &Skillset {
+        &self.skillset
+    }
+
+    pub fn configuration(&self) -> &Configuration {
+        &self.configuration
+    }
+
+    pub fn state(&self, resource: ResourceId) -> &State {
+        let id = self.configuration.valuation().get(resource);
+        self.skillset.get(id).unwrap()
+    }
+
+    pub fn is_running(&self, skill: SkillId) -> bool {
+        self.configuration.is_running(skill)
+    }
+
+    /// Whether the interrupt of the running skill has been requested.
+    pub fn is_interrupting(&self, skill: SkillId) -> bool {
+        self.interrupting[skill.index()]
+    }
+
+    /// The constraints that do not hold in the current configuration.
+    pub fn violations(&self) -> Vec<ConstraintId> {
+        self.skillset
+            .constraints()
+            .iter()
+            .filter(|x| !x.expr().eval(self.configuration.valuation()))
+            .map(|x| x.id())
+            .collect()
+    }
+
+    pub fn set_boxed_handler(&mut self, skill: &str, handler: Box<H>) -> Result<(), RuntimeError> {
+        let id = self.skill_id(skill)?;
+        self.handlers[id.index()] = Some(handler);
+        Ok(())
+    }
+
+    //---------- Requests ----------
+
+    /// The environment notifies the occurrence of an event.
+    pub fn notify(&mut self, event: &str) -> Result<Vec<Report>, RuntimeError> {
+        let event = match self.skillset.get_event_from_name(event) {
+            Some(event) => event,
+            None => return Err(RuntimeError::UnknownEvent(event.into())),
+        };
+        let action = Action::Event(event.id());
+        if let Some(guard) = event.guard() {
+            self.check(&action, "guard".into(), guard)?;
+        }
+        Ok(self.perform(action))
+    }
+
+    pub fn start(&mut self, skill: &str) -> Result<Vec<Report>, RuntimeError> {
+        let id = self.skill_id(skill)?;
+        if self.is_running(id) {
+            return Err(RuntimeError::AlreadyRunning(skill.into()));
+        }
+        let action = Action::Start(id);
+        for x in self.skillset.get(id).unwrap().preconditions() {
+            self.check(&action, format!("precondition {}", x), x.expr())?;
+        }
+        Ok(self.perform(action))
+    }
+
+    /// Interrupt the skill, or only request its interrupt if it is
+    /// interrupting.
+    pub fn interrupt(&mut self, skill: &str) -> Result<Vec<Report>, RuntimeError> {
+        let id = self.running_id(skill)?;
+        let skill = self.skillset.get(id).unwrap();
+        if !skill.interrupt().as_ref().is_some_and(|x| x.interrupting()) {
+            return Ok(self.terminate(Action::Interrupt(id)));
+        }
+        self.interrupting[id.index()] = true;
+        if let Some(handler) = self.handlers[id.index()].as_mut() {
+            handler.on_interrupt_request(skill);
+        }
+        Ok(vec![Report::Interrupting(id)])
+    }
+
+    /// The execution of the skill acknowledges the request of its interrupt.
+    pub fn interrupted(&mut self, skill: &str) -> Result<Vec<Report>, RuntimeError> {
+        let id = self.running_id(skill)?;
+        if !self.is_interrupting(id) {
+            return Err(RuntimeError::NotInterrupting(skill.into()));
+        }
+        Ok(self.terminate(Action::Interrupt(id)))
+    }
+
+    /// The skill terminates in a success mode.
+    pub fn succeed(&mut self, skill: &str, mode: &str) -> Result<Vec<Report>, RuntimeError> {
+        let id = self.running_id(skill)?;
+        let success = match self.skillset.get(id).unwrap().get_success_from_name(mode) {
+            Some(success) => success,
+            None => return Err(Self::unknown_mode(skill, mode)),
+        };
+        Ok(self.terminate(Action::Success(success.id())))
+    }
+
+    /// The skill terminates in a failure mode.
+    pub fn fail(&mut self, skill: &str, mode: &str) -> Result<Vec<Report>, RuntimeError> {
+        let id = self.running_id(skill)?;
+        let failure = match self.skillset.get(id).unwrap().get_failure_from_name(mode) {
+            Some(failure) => failure,
+            None => return Err(Self::unknown_mode(skill, mode)),
+        };
+        Ok(self.terminate(Action::Failure(failure.id())))
+    }
+
+    /// Poll the handlers of the running skills once, terminating the skills
+    /// that report a mode. A refused termination does not stop the polling of
+    /// the other skills.
+    pub fn step(&mut self) -> Vec<Result<Report, RuntimeError>> {
+        let mut reports = Vec::new();
+        for i in 0..self.handlers.len() {
+            let id = SkillId(i);
+            if !self.is_running(id) {
+                continue;
+            }
+            let skill = self.skillset.get(id).unwrap();
+            let status = match self.handlers[i].as_mut() {
+                Some(handler) => handler.on_step(skill),
+                None => continue,
+            };
+            let name = skill.name().to_string();
+            let result = match status {
+                Status::Running => continue,
+                Status::Success(mode) => self.succeed(&name, &mode),
+                Status::Failure(mode) => self.fail(&name, &mode),
+                Status::Interrupted => self.interrupted(&name),
+            };
+            match result {
+                Ok(v) => reports.extend(v.into_iter().map(Ok)),
+                Err(e) => reports.push(Err(e)),
+            }
+        }
+        reports
+    }
+
+    //---------- Semantics ----------
+
+    fn skill_id(&self, skill: &str) -> Result<SkillId, RuntimeError> {
+        match self.skillset.get_skill_from_name(skill) {
+            Some(skill) => Ok(skill.id()),
+            None => Err(RuntimeError::UnknownSkill(skill.into())),
+        }
+    }
+
+    fn running_id(&self, skill: &str) -> Result<SkillId, RuntimeError> {
+        let id = self.skill_id(skill)?;
+        if self.is_running(id) {
+            Ok(id)
+        } else {
+            Err(RuntimeError::NotRunning(skill.into()))
+        }
+    }
+
+    fn unknown_mode(skill: &str, mode: &str) -> RuntimeError {
+        RuntimeError::UnknownMode {
+            skill: skill.into(),
+            mode: mode.into(),
+        }
+    }
+
+    fn check(&self, action: &Action, condition: String, expr: &Expr) -> Result<(), RuntimeError> {
+        if expr.eval(self.configuration.valuation()) {
+            Ok(())
+        } else {
+            Err(RuntimeError::Disabled {
+                action: action.to_lang(&self.skillset),
+                condition,
+            })
+        }
+    }
+
+    /// Perform a termination, reporting first whether its postconditions do
+    /// not hold.
+    fn terminate(&mut self, action: Action) -> Vec<Report> {
+        let mut reports = Vec::new();
+        if !action
+            .postconditions(&self.skillset)
+            .eval(self.configuration.valuation())
+        {
+            reports.push(Report::Postcondition(action));
+        }
+        reports.extend(self.perform(action));
+        reports
+    }
+
+    /// Perform an accepted action and the invariant violations it causes.
+    fn perform(&mut self, action: Action) -> Vec<Report> {
+        let mut reports = Vec::new();
+        self.apply(action, &mut reports);
+        while let Some(violation) = self.violated_invariant() {
+            self.apply(violation, &mut reports);
+        }
+        reports.extend(self.violations().into_iter().map(Report::Violation));
+        reports
+    }
+
+    fn violated_invariant(&self) -> Option<Action> {
+        let valuation = self.configuration.valuation();
+        self.configuration
+            .running()
+            .into_iter()
+            .flat_map(|id| self.skillset.get(id).unwrap().invariants().iter())
+            .find(|x| !x.guard().eval(valuation))
+            .map(|x| Action::Invariant(x.id()))
+    }
+
+    fn apply(&mut self, action: Action, reports: &mut Vec<Report>) {
+        self.configuration = self.configuration.next(&self.skillset, &action);
+        reports.push(Report::Action(action));
+        let id = match action.skill() {
+            Some(id) => id,
+            None => return,
+        };
+        if action.running_after() == Some(false) {
+            self.interrupting[id.index()] = false;
+        }
+        let handler = match self.handlers[id.index()].as_mut() {
+            Some(handler) => handler,
+            None => return,
+        };
+        let skill = self.skillset.get(id).unwrap();
+        match action {
+            Action::Event(_) => {}
+            Action::Start(_) => handler.on_start(skill),
+            Action::Invariant(x) => {
+                handler.on_invariant_failure(skill, self.skillset.get(x).unwrap())
+            }
+            Action::Interrupt(_) => handler.on_interrupt(skill),
+            Action::Success(x) => handler.on_success(skill, self.skillset.get(x).unwrap()),
+            Action::Failure(x) => handler.on_failure(skill, self.skillset.get(x).unwrap()),
+        }
+    }
+}
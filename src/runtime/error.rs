@@ -0,0 +1,43 @@
+/// A request refused by the engine, its configuration being unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuntimeError {
+    UnknownEvent(String),
+    UnknownData(String),
+    UnknownSkill(String),
+    UnknownMode {
+        skill: String,
+        mode: String,
+    },
+    AlreadyRunning(String),
+    NotRunning(String),
+    /// The interrupt of the skill has not been requested.
+    NotInterrupting(String),
+    /// The action and the condition that does not hold.
+    Disabled {
+        action: String,
+        condition: String,
+    },
+}
+
+impl std::fmt::Display for RuntimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuntimeError::UnknownEvent(name) => write!(f, "unknown event {}", name),
+            RuntimeError::UnknownData(name) => write!(f, "unknown data {}", name),
+            RuntimeError::UnknownSkill(name) => write!(f, "unknown skill {}", name),
+            RuntimeError::UnknownMode { skill, mode } => {
+                write!(f, "unknown mode {} of skill {}", mode, skill)
+            }
+            RuntimeError::AlreadyRunning(name) => write!(f, "skill {} is already running", name),
+            RuntimeError::NotRunning(name) => write!(f, "skill {} is not running", name),
+            RuntimeError::NotInterrupting(name) => {
+                write!(f, "interrupt of skill {} is not requested", name)
+            }
+            RuntimeError::Disabled { action, condition } => {
+                write!(f, "{} refused: {} does not hold", action, condition)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RuntimeError {}
@@ -0,0 +1,35 @@
+use crate::model::*;
+
+/// What a running skill reports when polled by the engine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    /// Terminated in the success mode of this name.
+    Success(String),
+    /// Terminated in the failure mode of this name.
+    Failure(String),
+    /// Acknowledged the request of the interrupt.
+    Interrupted,
+}
+
+/// The user code of a skill, driven by the engine. Every callback but
+/// `on_step` is a notification of a change already applied on the resources.
+pub trait SkillHandler {
+    fn on_start(&mut self, _skill: &Skill) {}
+
+    /// Polled by `Engine::step` while the skill is running.
+    fn on_step(&mut self, skill: &Skill) -> Status;
+
+    /// The interrupt of the interrupting skill is requested, the skill
+    /// keeps running until `on_step` reports it interrupted.
+    fn on_interrupt_request(&mut self, _skill: &Skill) {}
+
+    fn on_interrupt(&mut self, _skill: &Skill) {}
+
+    /// The skill has been stopped by the violation of the invariant.
+    fn on_invariant_failure(&mut self, _skill: &Skill, _invariant: &Invariant) {}
+
+    fn on_success(&mut self, _skill: &Skill, _success: &Success) {}
+
+    fn on_failure(&mut self, _skill: &Skill, _failure: &Failure) {}
+}
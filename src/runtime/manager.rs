@@ -0,0 +1,261 @@
+use super::*;
+use crate::analysis::Configuration;
+use crate::model::*;
+use std::future::Future;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::oneshot::error::TryRecvError;
+use tokio::sync::{broadcast, oneshot};
+use tokio::time::{interval_at, Instant, Interval};
+
+// The manager shares an engine between tokio tasks: each running skill is a
+// task polling its execution, publishing a progress notification every period
+// of the skill, and cancelled through a channel (dropping the execution) when
+// the skill is interrupted or stopped by an invariant. Each data with a
+// period has a watchdog task reporting it stale when it is not refreshed in
+// time. The execution of an interrupting skill is not cancelled when its
+// interrupt is requested: it is notified through the report, and completes
+// the interrupt by returning `Outcome::Interrupted`. The engine is never
+// locked across an await.
+
+const CAPACITY: usize = 256;
+
+/// How a skill execution terminates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Success(String),
+    Failure(String),
+    /// The requested interrupt of an interrupting skill.
+    Interrupted,
+}
+
+/// What the manager publishes to its subscribers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Notification {
+    Report(Report),
+    /// Published every progress period while the skill is running.
+    Progress(SkillId),
+    /// The data has not been refreshed within its period.
+    Stale(DataId),
+    /// The termination of an execution refused by the engine, the skill
+    /// being interrupted instead.
+    Refused(SkillId, RuntimeError),
+}
+
+struct State {
+    engine: Engine<dyn SkillHandler + Send>,
+    cancellations: Vec<Option<oneshot::Sender<()>>>,
+    refreshed: Vec<(Instant, bool)>,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    notifications: broadcast::Sender<Notification>,
+}
+
+/// An async skill manager over an engine. Must be created within a tokio
+/// runtime.
+#[derive(Clone)]
+pub struct Manager {
+    shared: Arc<Shared>,
+}
+
+impl Manager {
+    pub fn new(skillset: Skillset) -> Self {
+        let cancellations = skillset.skills().iter().map(|_| None).collect();
+        let refreshed = skillset
+            .data()
+            .iter()
+            .map(|_| (Instant::now(), false))
+            .collect();
+        let periods: Vec<(DataId, Duration)> = skillset
+            .data()
+            .iter()
+            .filter_map(|x| x.period().map(|p| (x.id(), p)))
+            .collect();
+        let (notifications, _) = broadcast::channel(CAPACITY);
+        let state = State {
+            engine: Engine::with_handlers(skillset),
+            cancellations,
+            refreshed,
+        };
+        let shared = Arc::new(Shared {
+            state: Mutex::new(state),
+            notifications,
+        });
+        for (id, period) in periods {
+            tokio::spawn(watchdog(Arc::downgrade(&shared), id, period));
+        }
+        Self { shared }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Notification> {
+        self.shared.notifications.subscribe()
+    }
+
+    pub fn configuration(&self) -> Configuration {
+        self.lock().engine.configuration().clone()
+    }
+
+    pub fn skillset(&self) -> Skillset {
+        self.lock().engine.skillset().clone()
+    }
+
+    /// Set the handler notified of the lifecycle of the skill. Its `on_step`
+    /// is not polled, the execution given at start drives the skill.
+    pub fn set_handler<H: SkillHandler + Send + 'static>(
+        &self,
+        skill: &str,
+        handler: H,
+    ) -> Result<(), RuntimeError> {
+        self.lock()
+            .engine
+            .set_boxed_handler(skill, Box::new(handler))
+    }
+
+    //---------- Requests ----------
+
+    pub fn notify(&self, event: &str) -> Result<Vec<Report>, RuntimeError> {
+        let mut state = self.lock();
+        let reports = state.engine.notify(event)?;
+        self.publish(&mut state, &reports);
+        Ok(reports)
+    }
+
+    /// Start the skill and spawn its execution, that is dropped if the skill
+    /// is interrupted or stopped by an invariant.
+    pub fn start<F>(&self, skill: &str, execution: F) -> Result<Vec<Report>, RuntimeError>
+    where
+        F: Future<Output = Outcome> + Send + 'static,
+    {
+        let mut state = self.lock();
+        let reports = state.engine.start(skill)?;
+        self.publish(&mut state, &reports);
+        let skill = state.engine.skillset().get_skill_from_name(skill).unwrap();
+        let (id, period) = (skill.id(), skill.progress().as_ref().map(|x| x.period()));
+        if state.engine.is_running(id) {
+            let (sender, receiver) = oneshot::channel();
+            state.cancellations[id.index()] = Some(sender);
+            tokio::spawn(self.clone().run(id, period, execution, receiver));
+        }
+        Ok(reports)
+    }
+
+    pub fn interrupt(&self, skill: &str) -> Result<Vec<Report>, RuntimeError> {
+        let mut state = self.lock();
+        let reports = state.engine.interrupt(skill)?;
+        self.publish(&mut state, &reports);
+        Ok(reports)
+    }
+
+    /// The data has been received, restarting its watchdog.
+    pub fn refresh(&self, data: &str) -> Result<(), RuntimeError> {
+        let mut state = self.lock();
+        let id = match state.engine.skillset().get_data_from_name(data) {
+            Some(data) => data.id(),
+            None => return Err(RuntimeError::UnknownData(data.into())),
+        };
+        state.refreshed[id.index()] = (Instant::now(), false);
+        Ok(())
+    }
+
+    //---------- Tasks ----------
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.shared.state.lock().unwrap()
+    }
+
+    /// Send the reports, and cancel the executions of the skills they stop.
+    fn publish(&self, state: &mut State, reports: &[Report]) {
+        for report in reports {
+            if let Report::Action(action) = report {
+                if let (Some(id), Some(false)) = (action.skill(), action.running_after()) {
+                    if let Some(sender) = state.cancellations[id.index()].take() {
+                        let _ = sender.send(());
+                    }
+                }
+            }
+            let _ = self
+                .shared
+                .notifications
+                .send(Notification::Report(*report));
+        }
+    }
+
+    async fn run<F>(
+        self,
+        id: SkillId,
+        period: Option<Duration>,
+        execution: F,
+        mut cancellation: oneshot::Receiver<()>,
+    ) where
+        F: Future<Output = Outcome> + Send + 'static,
+    {
+        let mut progress = period.map(|x| interval_at(Instant::now() + x, x));
+        tokio::pin!(execution);
+        let outcome = loop {
+            tokio::select! {
+                outcome = &mut execution => break outcome,
+                _ = &mut cancellation => return,
+                _ = tick(&mut progress) => {
+                    let _ = self.shared.notifications.send(Notification::Progress(id));
+                }
+            }
+        };
+        let mut state = self.lock();
+        // Stopped meanwhile, the skill may already run another execution
+        if !matches!(cancellation.try_recv(), Err(TryRecvError::Empty)) {
+            return;
+        }
+        // The cancellation is cleared when publishing the termination
+        let name = state.engine.skillset().get(id).unwrap().name().to_string();
+        let result = match &outcome {
+            Outcome::Success(mode) => state.engine.succeed(&name, mode),
+            Outcome::Failure(mode) => state.engine.fail(&name, mode),
+            Outcome::Interrupted => state.engine.interrupted(&name),
+        };
+        match result {
+            Ok(reports) => self.publish(&mut state, &reports),
+            Err(e) => {
+                let _ = self.shared.notifications.send(Notification::Refused(id, e));
+                // Without an execution anymore, the skill cannot keep running
+                if let Ok(reports) = state.engine.interrupt(&name) {
+                    self.publish(&mut state, &reports);
+                }
+                if state.engine.is_interrupting(id) {
+                    if let Ok(reports) = state.engine.interrupted(&name) {
+                        self.publish(&mut state, &reports);
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Report the data stale once each time it is not refreshed within its
+/// period, until the manager is dropped.
+async fn watchdog(shared: Weak<Shared>, id: DataId, period: Duration) {
+    let mut interval = interval_at(Instant::now() + period, period);
+    loop {
+        interval.tick().await;
+        let shared = match shared.upgrade() {
+            Some(shared) => shared,
+            None => return,
+        };
+        let mut state = shared.state.lock().unwrap();
+        let (refreshed, reported) = &mut state.refreshed[id.index()];
+        if !*reported && refreshed.elapsed() > period {
+            *reported = true;
+            let _ = shared.notifications.send(Notification::Stale(id));
+        }
+    }
+}
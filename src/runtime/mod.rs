@@ -0,0 +1,13 @@
+pub mod error;
+pub use error::*;
+
+pub mod handler;
+pub use handler::*;
+
+pub mod engine;
+pub use engine::*;
+
+#[cfg(feature = "async")]
+pub mod manager;
+#[cfg(feature = "async")]
+pub use manager::*;
@@ -0,0 +1,350 @@
+use std::collections::HashMap;
+
+/// A node of a manager, FALSE and TRUE being the terminal nodes.
+pub type Bdd = usize;
+
+pub const FALSE: Bdd = 0;
+pub const TRUE: Bdd = 1;
+
+const TERMINAL: u32 = u32::MAX;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Node {
+    var: u32,
+    low: Bdd,
+    high: Bdd,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+/// Reduced ordered binary decision diagrams, variables being ordered by their
+/// index.
+#[derive(Debug)]
+pub struct BddManager {
+    nodes: Vec<Node>,
+    unique: HashMap<Node, Bdd>,
+    cache: HashMap<(Op, Bdd, Bdd), Bdd>,
+}
+
+impl Default for BddManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BddManager {
+    pub fn new() -> Self {
+        let terminal = |x| Node {
+            var: TERMINAL,
+            low: x,
+            high: x,
+        };
+        Self {
+            nodes: vec![terminal(FALSE), terminal(TRUE)],
+            unique: HashMap::new(),
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len() == 2
+    }
+
+    fn var_of(&self, f: Bdd) -> u32 {
+        self.nodes[f].var
+    }
+
+    fn cofactors(&self, f: Bdd, var: u32) -> (Bdd, Bdd) {
+        let node = self.nodes[f];
+        if node.var == var {
+            (node.low, node.high)
+        } else {
+            (f, f)
+        }
+    }
+
+    fn mk(&mut self, var: u32, low: Bdd, high: Bdd) -> Bdd {
+        if low == high {
+            return low;
+        }
+        let node = Node { var, low, high };
+        if let Some(f) = self.unique.get(&node) {
+            return *f;
+        }
+        let f = self.nodes.len();
+        self.nodes.push(node);
+        self.unique.insert(node, f);
+        f
+    }
+
+    //---------- Operations ----------
+
+    pub fn var(&mut self, var: u32) -> Bdd {
+        self.mk(var, FALSE, TRUE)
+    }
+
+    pub fn not(&mut self, f: Bdd) -> Bdd {
+        self.apply(Op::Xor, f, TRUE)
+    }
+
+    pub fn and(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        self.apply(Op::And, f, g)
+    }
+
+    pub fn or(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        self.apply(Op::Or, f, g)
+    }
+
+    pub fn xor(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        self.apply(Op::Xor, f, g)
+    }
+
+    pub fn iff(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        let x = self.xor(f, g);
+        self.not(x)
+    }
+
+    pub fn implies(&mut self, f: Bdd, g: Bdd) -> Bdd {
+        let x = self.not(f);
+        self.or(x, g)
+    }
+
+    pub fn and_all<I: IntoIterator<Item = Bdd>>(&mut self, fs: I) -> Bdd {
+        fs.into_iter().fold(TRUE, |acc, f| self.and(acc, f))
+    }
+
+    pub fn or_all<I: IntoIterator<Item = Bdd>>(&mut self, fs: I) -> Bdd {
+        fs.into_iter().fold(FALSE, |acc, f| self.or(acc, f))
+    }
+
+    fn apply(&mut self, op: Op, f: Bdd, g: Bdd) -> Bdd {
+        match op {
+            Op::And => {
+                if f == FALSE || g == FALSE {
+                    return FALSE;
+                }
+                if f == TRUE || f == g {
+                    return g;
+                }
+                if g == TRUE {
+                    return f;
+                }
+            }
+            Op::Or => {
+                if f == TRUE || g == TRUE {
+                    return TRUE;
+                }
+                if f == FALSE || f == g {
+                    return g;
+                }
+                if g == FALSE {
+                    return f;
+                }
+            }
+            Op::Xor => {
+                if f == g {
+                    return FALSE;
+                }
+                if f == FALSE {
+                    return g;
+                }
+                if g == FALSE {
+                    return f;
+                }
+            }
+        }
+        let key = (op, f.min(g), f.max(g));
+        if let Some(r) = self.cache.get(&key) {
+            return *r;
+        }
+        let var = self.var_of(f).min(self.var_of(g));
+        let (f0, f1) = self.cofactors(f, var);
+        let (g0, g1) = self.cofactors(g, var);
+        let low = self.apply(op, f0, g0);
+        let high = self.apply(op, f1, g1);
+        let r = self.mk(var, low, high);
+        self.cache.insert(key, r);
+        r
+    }
+
+    //---------- Quantification ----------
+
+    /// The existential quantification of the variables selected by the mask.
+    pub fn exists(&mut self, f: Bdd, mask: &[bool]) -> Bdd {
+        let mut cache = HashMap::new();
+        self.exists_rec(f, mask, &mut cache)
+    }
+
+    fn exists_rec(&mut self, f: Bdd, mask: &[bool], cache: &mut HashMap<Bdd, Bdd>) -> Bdd {
+        if f <= TRUE {
+            return f;
+        }
+        if let Some(r) = cache.get(&f) {
+            return *r;
+        }
+        let Node { var, low, high } = self.nodes[f];
+        let low = self.exists_rec(low, mask, cache);
+        let high = self.exists_rec(high, mask, cache);
+        let r = if mask[var as usize] {
+            self.or(low, high)
+        } else {
+            self.mk(var, low, high)
+        };
+        cache.insert(f, r);
+        r
+    }
+
+    /// The existential quantification of the conjunction, without building
+    /// the conjunction.
+    pub fn and_exists(&mut self, f: Bdd, g: Bdd, mask: &[bool]) -> Bdd {
+        let mut cache = HashMap::new();
+        let mut exists_cache = HashMap::new();
+        self.and_exists_rec(f, g, mask, &mut cache, &mut exists_cache)
+    }
+
+    fn and_exists_rec(
+        &mut self,
+        f: Bdd,
+        g: Bdd,
+        mask: &[bool],
+        cache: &mut HashMap<(Bdd, Bdd), Bdd>,
+        exists_cache: &mut HashMap<Bdd, Bdd>,
+    ) -> Bdd {
+        if f == FALSE || g == FALSE {
+            return FALSE;
+        }
+        if f == TRUE && g == TRUE {
+            return TRUE;
+        }
+        if f == TRUE {
+            return self.exists_rec(g, mask, exists_cache);
+        }
+        if g == TRUE {
+            return self.exists_rec(f, mask, exists_cache);
+        }
+        let key = (f.min(g), f.max(g));
+        if let Some(r) = cache.get(&key) {
+            return *r;
+        }
+        let var = self.var_of(f).min(self.var_of(g));
+        let (f0, f1) = self.cofactors(f, var);
+        let (g0, g1) = self.cofactors(g, var);
+        let low = self.and_exists_rec(f0, g0, mask, cache, exists_cache);
+        let r = if mask[var as usize] {
+            if low == TRUE {
+                TRUE
+            } else {
+                let high = self.and_exists_rec(f1, g1, mask, cache, exists_cache);
+                self.or(low, high)
+            }
+        } else {
+            let high = self.and_exists_rec(f1, g1, mask, cache, exists_cache);
+            self.mk(var, low, high)
+        };
+        cache.insert(key, r);
+        r
+    }
+
+    /// Rename the variables with an order preserving mapping.
+    pub fn rename<F: Fn(u32) -> u32>(&mut self, f: Bdd, map: &F) -> Bdd {
+        let mut cache = HashMap::new();
+        self.rename_rec(f, map, &mut cache)
+    }
+
+    fn rename_rec<F: Fn(u32) -> u32>(
+        &mut self,
+        f: Bdd,
+        map: &F,
+        cache: &mut HashMap<Bdd, Bdd>,
+    ) -> Bdd {
+        if f <= TRUE {
+            return f;
+        }
+        if let Some(r) = cache.get(&f) {
+            return *r;
+        }
+        let Node { var, low, high } = self.nodes[f];
+        let low = self.rename_rec(low, map, cache);
+        let high = self.rename_rec(high, map, cache);
+        let r = self.mk(map(var), low, high);
+        cache.insert(f, r);
+        r
+    }
+
+    //---------- Assignment ----------
+
+    /// Some assignment satisfying the function, the variables that are not
+    /// assigned can take any value.
+    pub fn sat_one(&self, f: Bdd) -> Option<Vec<(u32, bool)>> {
+        if f == FALSE {
+            return None;
+        }
+        let mut v = Vec::new();
+        let mut current = f;
+        while current > TRUE {
+            let node = self.nodes[current];
+            if node.low != FALSE {
+                v.push((node.var, false));
+                current = node.low;
+            } else {
+                v.push((node.var, true));
+                current = node.high;
+            }
+        }
+        Some(v)
+    }
+
+    /// The number of satisfying assignments of the variables mapped to levels
+    /// 0 to levels - 1, the function depending on these variables only.
+    pub fn sat_count<F: Fn(u32) -> u32>(&self, f: Bdd, levels: u32, level: &F) -> f64 {
+        let mut cache = HashMap::new();
+        let level_of = |x: Bdd| {
+            if x <= TRUE {
+                levels
+            } else {
+                level(self.nodes[x].var)
+            }
+        };
+        let count = self.sat_count_rec(f, levels, level, &mut cache);
+        count * 2f64.powi(level_of(f) as i32)
+    }
+
+    fn sat_count_rec<F: Fn(u32) -> u32>(
+        &self,
+        f: Bdd,
+        levels: u32,
+        level: &F,
+        cache: &mut HashMap<Bdd, f64>,
+    ) -> f64 {
+        if f <= TRUE {
+            return f as f64;
+        }
+        if let Some(r) = cache.get(&f) {
+            return *r;
+        }
+        let node = self.nodes[f];
+        let l = level(node.var);
+        let level_of = |x: Bdd| {
+            if x <= TRUE {
+                levels
+            } else {
+                level(self.nodes[x].var)
+            }
+        };
+        let low = self.sat_count_rec(node.low, levels, level, cache)
+            * 2f64.powi((level_of(node.low) - l - 1) as i32);
+        let high = self.sat_count_rec(node.high, levels, level, cache)
+            * 2f64.powi((level_of(node.high) - l - 1) as i32);
+        cache.insert(f, low + high);
+        low + high
+    }
+}
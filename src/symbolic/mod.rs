@@ -0,0 +1,5 @@
+pub mod bdd;
+pub use bdd::*;
+
+pub mod space;
+pub use space::*;
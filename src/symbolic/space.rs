@@ -0,0 +1,376 @@
+use super::*;
+use crate::analysis::Configuration;
+use crate::model::*;
+
+// Encoding: the states of each resource are numbered and encoded in binary
+// with as few bits as possible, each skill has one bit (running). A bit k of
+// the state is the BDD variable 2k, and 2k + 1 in the next state, so that
+// current and next variables are interleaved.
+
+fn current(bit: u32) -> u32 {
+    2 * bit
+}
+
+fn next(bit: u32) -> u32 {
+    2 * bit + 1
+}
+
+fn bits_for(states: usize) -> u32 {
+    let mut bits = 0;
+    while (1usize << bits) < states {
+        bits += 1;
+    }
+    bits
+}
+
+/// The configurations reachable from the initial one, as BDDs over the bits
+/// of the configurations, by distance to the initial configuration.
+#[derive(Debug)]
+pub struct SymbolicSpace<'a> {
+    skillset: &'a Skillset,
+    manager: BddManager,
+    resources: Vec<Vec<u32>>,
+    skills: Vec<u32>,
+    bits: u32,
+    actions: Vec<(Action, Bdd)>,
+    layers: Vec<Bdd>,
+    reachable: Bdd,
+}
+
+impl<'a> SymbolicSpace<'a> {
+    pub fn explore(skillset: &'a Skillset) -> Self {
+        let mut bits = 0;
+        let mut resources = Vec::new();
+        for resource in skillset.resources() {
+            let n = bits_for(resource.states().len());
+            resources.push((bits..bits + n).collect());
+            bits += n;
+        }
+        let mut skills = Vec::new();
+        for _ in skillset.skills() {
+            skills.push(bits);
+            bits += 1;
+        }
+        let mut space = Self {
+            skillset,
+            manager: BddManager::new(),
+            resources,
+            skills,
+            bits,
+            actions: Vec::new(),
+            layers: Vec::new(),
+            reachable: FALSE,
+        };
+        space.actions = skillset
+            .actions()
+            .into_iter()
+            .map(|x| (x, space.relation(&x)))
+            .collect();
+        let initial = space.configuration(&Configuration::initial(skillset), false);
+        space.layers.push(initial);
+        space.reachable = initial;
+        let mut frontier = initial;
+        loop {
+            let image = space.image(frontier);
+            let not_reached = space.manager.not(space.reachable);
+            frontier = space.manager.and(image, not_reached);
+            if frontier == FALSE {
+                break;
+            }
+            space.layers.push(frontier);
+            space.reachable = space.manager.or(space.reachable, frontier);
+        }
+        space
+    }
+
+    //---------- Encoding ----------
+
+    fn code(&mut self, bits: &[u32], value: usize, is_next: bool) -> Bdd {
+        let mut f = TRUE;
+        for (i, bit) in bits.iter().enumerate() {
+            let var = if is_next { next(*bit) } else { current(*bit) };
+            let x = self.manager.var(var);
+            let x = if value & (1 << i) != 0 {
+                x
+            } else {
+                self.manager.not(x)
+            };
+            f = self.manager.and(f, x);
+        }
+        f
+    }
+
+    fn state(&mut self, id: StateId, is_next: bool) -> Bdd {
+        let bits = self.resources[id.resource().index()].clone();
+        self.code(&bits, id.index(), is_next)
+    }
+
+    fn running(&mut self, id: SkillId, is_next: bool) -> Bdd {
+        let bit = self.skills[id.index()];
+        self.manager
+            .var(if is_next { next(bit) } else { current(bit) })
+    }
+
+    fn configuration(&mut self, configuration: &Configuration, is_next: bool) -> Bdd {
+        let mut f = TRUE;
+        for id in configuration.valuation().states().clone() {
+            let x = self.state(id, is_next);
+            f = self.manager.and(f, x);
+        }
+        for skill in self.skillset.skills() {
+            let x = self.running(skill.id(), is_next);
+            let x = if configuration.is_running(skill.id()) {
+                x
+            } else {
+                self.manager.not(x)
+            };
+            f = self.manager.and(f, x);
+        }
+        f
+    }
+
+    /// The expression on the current configuration.
+    pub fn expr(&mut self, expr: &Expr) -> Bdd {
+        match expr {
+            Expr::True => TRUE,
+            Expr::False => FALSE,
+            Expr::ResourceEq(_, state) => self.state(state.resolved(), false),
+            Expr::ResourceNe(_, state) => {
+                let x = self.state(state.resolved(), false);
+                self.manager.not(x)
+            }
+            Expr::Not(e) => {
+                let x = self.expr(e);
+                self.manager.not(x)
+            }
+            Expr::And(l, r) => {
+                let (l, r) = (self.expr(l), self.expr(r));
+                self.manager.and(l, r)
+            }
+            Expr::Or(l, r) => {
+                let (l, r) = (self.expr(l), self.expr(r));
+                self.manager.or(l, r)
+            }
+            Expr::Implies(l, r) => {
+                let (l, r) = (self.expr(l), self.expr(r));
+                self.manager.implies(l, r)
+            }
+        }
+    }
+
+    fn unchanged(&mut self, bits: &[u32]) -> Bdd {
+        let mut f = TRUE;
+        for bit in bits {
+            let x = self.manager.var(current(*bit));
+            let y = self.manager.var(next(*bit));
+            let e = self.manager.iff(x, y);
+            f = self.manager.and(f, e);
+        }
+        f
+    }
+
+    pub fn enabled(&mut self, action: &Action) -> Bdd {
+        let guard = action.guard(self.skillset);
+        let mut f = self.expr(&guard);
+        if let Some(id) = action.skill() {
+            let running = self.running(id, false);
+            let status = match action {
+                Action::Start(_) => self.manager.not(running),
+                _ => running,
+            };
+            f = self.manager.and(f, status);
+        }
+        f
+    }
+
+    /// The relation between the current and the next configuration when the
+    /// action occurs.
+    fn relation(&mut self, action: &Action) -> Bdd {
+        let mut f = self.enabled(action);
+        for resource in self.skillset.resources() {
+            let target = action
+                .effects(self.skillset)
+                .iter()
+                .rev()
+                .find(|x| x.resource().resolved() == resource.id());
+            let x = match target {
+                Some(effect) => self.state(effect.state().resolved(), true),
+                None => {
+                    let bits = self.resources[resource.id().index()].clone();
+                    self.unchanged(&bits)
+                }
+            };
+            f = self.manager.and(f, x);
+        }
+        for skill in self.skillset.skills() {
+            let x = match action.running_after() {
+                Some(running) if action.skill() == Some(skill.id()) => {
+                    let x = self.running(skill.id(), true);
+                    if running {
+                        x
+                    } else {
+                        self.manager.not(x)
+                    }
+                }
+                _ => {
+                    let bit = self.skills[skill.id().index()];
+                    self.unchanged(&[bit])
+                }
+            };
+            f = self.manager.and(f, x);
+        }
+        f
+    }
+
+    fn mask(&self, is_next: bool) -> Vec<bool> {
+        (0..2 * self.bits)
+            .map(|x| (x % 2 == 1) == is_next)
+            .collect()
+    }
+
+    /// The configurations reached from the set when the action occurs.
+    pub fn post(&mut self, action: &Action, set: Bdd) -> Bdd {
+        let mask = self.mask(false);
+        let relation = self.relation(action);
+        let f = self.manager.and_exists(set, relation, &mask);
+        self.manager.rename(f, &|x| x - 1)
+    }
+
+    /// The configurations from which some action leads to the set.
+    pub fn pre(&mut self, set: Bdd) -> Bdd {
+        let mask = self.mask(true);
+        let next = self.manager.rename(set, &|x| x + 1);
+        let mut f = FALSE;
+        for i in 0..self.actions.len() {
+            let relation = self.actions[i].1;
+            let x = self.manager.and_exists(relation, next, &mask);
+            f = self.manager.or(f, x);
+        }
+        f
+    }
+
+    fn image(&mut self, set: Bdd) -> Bdd {
+        let mask = self.mask(false);
+        let mut f = FALSE;
+        for i in 0..self.actions.len() {
+            let relation = self.actions[i].1;
+            let x = self.manager.and_exists(set, relation, &mask);
+            f = self.manager.or(f, x);
+        }
+        self.manager.rename(f, &|x| x - 1)
+    }
+
+    //---------- Configuration ----------
+
+    /// Some configuration of a non empty set.
+    fn pick(&self, set: Bdd) -> Option<Configuration> {
+        let assignment = self.manager.sat_one(set)?;
+        let value = |bit: u32| {
+            assignment
+                .iter()
+                .any(|(var, value)| *var == current(bit) && *value)
+        };
+        let mut states = Vec::new();
+        for (resource, bits) in self.skillset.resources().iter().zip(self.resources.iter()) {
+            let index = bits
+                .iter()
+                .enumerate()
+                .filter(|(_, bit)| value(**bit))
+                .fold(0, |acc, (i, _)| acc | (1 << i));
+            states.push(resource.states()[index].id());
+        }
+        let running = self.skills.iter().map(|bit| value(*bit)).collect();
+        Some(Configuration::new(Valuation::new(states), running))
+    }
+
+    /// The shortest sequence of actions from the initial configuration to a
+    /// configuration of the set, with this configuration.
+    pub fn witness(&mut self, set: Bdd) -> Option<(Configuration, Vec<Action>)> {
+        let i = (0..self.layers.len()).find(|i| self.manager.and(self.layers[*i], set) != FALSE)?;
+        let layer = self.manager.and(self.layers[i], set);
+        let target = self.pick(layer).unwrap();
+        let mut configuration = target.clone();
+        let mut actions = Vec::new();
+        let mask = self.mask(true);
+        for j in (0..i).rev() {
+            let next = self.configuration(&configuration, true);
+            for k in 0..self.actions.len() {
+                let (action, relation) = self.actions[k];
+                let pre = self.manager.and_exists(relation, next, &mask);
+                let pre = self.manager.and(pre, self.layers[j]);
+                if pre != FALSE {
+                    configuration = self.pick(pre).unwrap();
+                    actions.push(action);
+                    break;
+                }
+            }
+        }
+        actions.reverse();
+        Some((target, actions))
+    }
+
+    //---------- Queries ----------
+
+    pub fn reachable(&self) -> Bdd {
+        self.reachable
+    }
+
+    /// The number of configurations of a set.
+    pub fn count(&self, set: Bdd) -> f64 {
+        self.manager.sat_count(set, self.bits, &|x| x / 2)
+    }
+
+    /// The number of steps of the longest shortest path from the initial
+    /// configuration.
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    pub fn manager(&mut self) -> &mut BddManager {
+        &mut self.manager
+    }
+
+    pub fn is_reachable(&mut self, expr: &Expr) -> Option<(Configuration, Vec<Action>)> {
+        let f = self.expr(expr);
+        self.witness(f)
+    }
+
+    /// The reachable configurations where no action is enabled.
+    pub fn deadlocks(&mut self) -> Bdd {
+        let mut enabled = FALSE;
+        for i in 0..self.actions.len() {
+            let action = self.actions[i].0;
+            let x = self.enabled(&action);
+            enabled = self.manager.or(enabled, x);
+        }
+        let disabled = self.manager.not(enabled);
+        self.manager.and(self.reachable, disabled)
+    }
+
+    /// The reachable configurations from which a configuration of the set can
+    /// be reached.
+    pub fn backward_reachable(&mut self, set: Bdd) -> Bdd {
+        let mut reached = self.manager.and(set, self.reachable);
+        loop {
+            let pre = self.pre(reached);
+            let pre = self.manager.and(pre, self.reachable);
+            let next = self.manager.or(reached, pre);
+            if next == reached {
+                return reached;
+            }
+            reached = next;
+        }
+    }
+
+    /// A reachable configuration violating the property.
+    pub fn check(&mut self, property: &Expr) -> Option<(Configuration, Vec<Action>)> {
+        let f = self.expr(property);
+        let violated = self.manager.not(f);
+        self.witness(violated)
+    }
+
+    pub fn can_occur(&mut self, action: &Action) -> bool {
+        let f = self.enabled(action);
+        self.manager.and(f, self.reachable) != FALSE
+    }
+}
@@ -0,0 +1,98 @@
+use rl_model::analysis::*;
+use rl_model::load_skillset;
+
+/// The messages of the errors of the analyses of the fixture.
+fn errors(file: &str) -> Vec<String> {
+    let skillset = match load_skillset(&format!("examples/tests/verif/{}", file)) {
+        Ok(skillset) => skillset,
+        Err(e) => panic!("{}", e),
+    };
+    analyze(&skillset)
+        .into_iter()
+        .filter(|x| x.severity() == Severity::Error)
+        .map(|x| x.message().to_string())
+        .collect()
+}
+
+#[test]
+fn postconditions() {
+    let postconditions = |file| -> Vec<String> {
+        errors(file)
+            .into_iter()
+            .filter(|x| x.starts_with("postconditions"))
+            .collect()
+    };
+    assert!(postconditions("postcondition_1.rl").is_empty());
+    assert_eq!(
+        postconditions("postcondition_err_1.rl"),
+        vec!["postconditions of interrupt of skill S cannot hold with the invariants"]
+    );
+    assert_eq!(
+        postconditions("postcondition_err_2.rl"),
+        vec!["postconditions of success s of skill S cannot hold with the invariants"]
+    );
+    assert_eq!(
+        postconditions("postcondition_err_3.rl"),
+        vec!["postconditions of failure f of skill S cannot hold with the invariants"]
+    );
+    assert!(postconditions("postcondition_err_4.rl").contains(
+        &"postconditions of success s of skill S contradict its effects: R -> C is not a transition from B"
+            .to_string()
+    ));
+}
+
+#[test]
+fn invariants() {
+    assert_eq!(
+        errors("invariant_err_1.rl")
+            .into_iter()
+            .filter(|x| x.starts_with("invariant"))
+            .collect::<Vec<_>>(),
+        vec!["invariant p2 of skill S does not hold after start in R == B"]
+    );
+    let skillset = match load_skillset("examples/tests/verif/invariant_err_2.rl") {
+        Ok(skillset) => skillset,
+        Err(e) => panic!("{}", e),
+    };
+    let redundant: Vec<String> = redundant_invariants(&skillset)
+        .into_iter()
+        .map(|x| x.message().to_string())
+        .collect();
+    assert_eq!(
+        redundant,
+        vec!["invariant p2 of skill S is implied by invariant p1"]
+    );
+    assert!(errors("invariant_err_3.rl").contains(
+        &"effect R -> A of invariant p1 of skill S is not a transition from C when the invariant is violated"
+            .to_string()
+    ));
+    assert!(errors("invariant_err_5.rl")
+        .contains(&"effects of invariant p1 of skill S can violate constraint safe".to_string()));
+}
+
+#[test]
+fn deadlock_positions() {
+    let position = |file: &str, symbolic: bool| {
+        let skillset = match load_skillset(&format!("examples/tests/verif/{}", file)) {
+            Ok(skillset) => skillset,
+            Err(e) => panic!("{}", e),
+        };
+        let diagnostics = if symbolic {
+            analyze_symbolic(&skillset)
+        } else {
+            analyze(&skillset)
+        };
+        let deadlock = diagnostics
+            .into_iter()
+            .find(|x| x.message().starts_with("deadlock"))
+            .unwrap();
+        let position = deadlock.position().unwrap();
+        (position.line, position.column)
+    };
+    for symbolic in [false, true] {
+        // at the skill left running
+        assert_eq!(position("postcondition_1.rl", symbolic), (13, 11));
+        // at the skillset when no skill runs
+        assert_eq!(position("invariant_err_1.rl", symbolic), (1, 10));
+    }
+}
@@ -0,0 +1,158 @@
+use rl_model::symbolic::*;
+
+fn vars(manager: &mut BddManager, n: u32) -> Vec<Bdd> {
+    (0..n).map(|i| manager.var(i)).collect()
+}
+
+/// Some functions over the variables, built with a fixed pseudo random
+/// sequence of operations.
+fn functions(manager: &mut BddManager, n: u32) -> Vec<Bdd> {
+    let mut v = vars(manager, n);
+    let mut seed: u64 = 7;
+    for _ in 0..40 {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        let f = v[(seed >> 33) as usize % v.len()];
+        let g = v[(seed >> 17) as usize % v.len()];
+        let x = match seed % 4 {
+            0 => manager.and(f, g),
+            1 => manager.or(f, g),
+            2 => manager.xor(f, g),
+            _ => manager.not(f),
+        };
+        v.push(x);
+    }
+    v
+}
+
+#[test]
+fn constants() {
+    let mut manager = BddManager::new();
+    assert!(manager.is_empty());
+    assert_eq!(manager.not(TRUE), FALSE);
+    assert_eq!(manager.not(FALSE), TRUE);
+    let x = manager.var(0);
+    assert!(!manager.is_empty());
+    assert_eq!(manager.and(x, FALSE), FALSE);
+    assert_eq!(manager.and(x, TRUE), x);
+    assert_eq!(manager.or(x, TRUE), TRUE);
+    assert_eq!(manager.or(x, FALSE), x);
+    let not_x = manager.not(x);
+    assert_eq!(manager.and(x, not_x), FALSE);
+    assert_eq!(manager.or(x, not_x), TRUE);
+    assert_eq!(manager.and_all(vec![]), TRUE);
+    assert_eq!(manager.or_all(vec![]), FALSE);
+}
+
+#[test]
+fn canonical() {
+    let mut manager = BddManager::new();
+    let v = vars(&mut manager, 3);
+    let (x, y, z) = (v[0], v[1], v[2]);
+    // commutativity
+    assert_eq!(manager.and(x, y), manager.and(y, x));
+    assert_eq!(manager.or(x, y), manager.or(y, x));
+    // De Morgan
+    let and = manager.and(x, y);
+    let not_and = manager.not(and);
+    let (not_x, not_y) = (manager.not(x), manager.not(y));
+    assert_eq!(not_and, manager.or(not_x, not_y));
+    // distributivity
+    let or = manager.or(y, z);
+    let left = manager.and(x, or);
+    let (xy, xz) = (manager.and(x, y), manager.and(x, z));
+    assert_eq!(left, manager.or(xy, xz));
+    // derived operators
+    assert_eq!(manager.xor(x, x), FALSE);
+    assert_eq!(manager.iff(x, x), TRUE);
+    assert_eq!(manager.implies(x, y), manager.or(not_x, y));
+    let xor = manager.xor(x, y);
+    let (x_not_y, not_x_y) = (manager.and(x, not_y), manager.and(not_x, y));
+    assert_eq!(xor, manager.or(x_not_y, not_x_y));
+    assert_eq!(manager.and_all(vec![x, y, z]), manager.and(xy, z));
+    assert_eq!(manager.or_all(vec![x, y, z]), manager.or(x, or));
+}
+
+#[test]
+fn quantification() {
+    let mut manager = BddManager::new();
+    let v = vars(&mut manager, 2);
+    let mask = [false, true];
+    let and = manager.and(v[0], v[1]);
+    assert_eq!(manager.exists(and, &mask), v[0]);
+    let xor = manager.xor(v[0], v[1]);
+    assert_eq!(manager.exists(xor, &mask), TRUE);
+    assert_eq!(manager.exists(v[0], &mask), v[0]);
+
+    let mut manager = BddManager::new();
+    let functions = functions(&mut manager, 4);
+    let masks = [
+        [true, false, true, false],
+        [false, true, false, true],
+        [true, true, false, false],
+    ];
+    for f in functions.iter() {
+        for g in functions.iter() {
+            for mask in masks.iter() {
+                let and = manager.and(*f, *g);
+                let expected = manager.exists(and, mask);
+                assert_eq!(manager.and_exists(*f, *g, mask), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn rename() {
+    let mut manager = BddManager::new();
+    let v = vars(&mut manager, 4);
+    let f = manager.and(v[0], v[2]);
+    let g = manager.or(f, v[1]);
+    let renamed = manager.rename(g, &|x| x + 1);
+    let expected = manager.and(v[1], v[3]);
+    let expected = manager.or(expected, v[2]);
+    assert_eq!(renamed, expected);
+    assert_eq!(manager.rename(renamed, &|x| x - 1), g);
+}
+
+#[test]
+fn sat_one() {
+    let mut manager = BddManager::new();
+    assert_eq!(manager.sat_one(FALSE), None);
+    assert_eq!(manager.sat_one(TRUE), Some(Vec::new()));
+    for f in functions(&mut manager, 4) {
+        let assignment = match manager.sat_one(f) {
+            Some(assignment) => assignment,
+            None => {
+                assert_eq!(f, FALSE);
+                continue;
+            }
+        };
+        let mut cube = TRUE;
+        for (var, value) in assignment {
+            let x = manager.var(var);
+            let x = if value { x } else { manager.not(x) };
+            cube = manager.and(cube, x);
+        }
+        assert_eq!(manager.implies(cube, f), TRUE);
+    }
+}
+
+#[test]
+fn sat_count() {
+    let mut manager = BddManager::new();
+    let v = vars(&mut manager, 3);
+    let count = |manager: &BddManager, f| manager.sat_count(f, 3, &|x| x);
+    assert_eq!(count(&manager, FALSE), 0.0);
+    assert_eq!(count(&manager, TRUE), 8.0);
+    assert_eq!(count(&manager, v[0]), 4.0);
+    assert_eq!(count(&manager, v[2]), 4.0);
+    let and = manager.and(v[0], v[1]);
+    assert_eq!(count(&manager, and), 2.0);
+    let or = manager.or(v[0], v[2]);
+    assert_eq!(count(&manager, or), 6.0);
+    let xor = manager.xor(v[1], v[2]);
+    assert_eq!(count(&manager, xor), 4.0);
+    // the variables 2k are counted as the levels k
+    let count = manager.sat_count(v[2], 2, &|x| x / 2);
+    assert_eq!(count, 2.0);
+}
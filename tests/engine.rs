@@ -0,0 +1,237 @@
+use rl_model::load_skillset;
+use rl_model::model::*;
+use rl_model::runtime::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn engine() -> Engine {
+    match load_skillset("examples/tests/runtime/robot_1.rl") {
+        Ok(skillset) => Engine::new(skillset),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn skill(engine: &Engine, name: &str) -> SkillId {
+    engine.skillset().get_skill_from_name(name).unwrap().id()
+}
+
+fn state(engine: &Engine, resource: &str) -> String {
+    let resource = engine
+        .skillset()
+        .resources()
+        .iter()
+        .find(|x| x.name() == resource)
+        .unwrap()
+        .id();
+    engine.state(resource).name().to_string()
+}
+
+fn invariant(engine: &Engine, skill: &str, name: &str) -> Action {
+    let skill = engine.skillset().get_skill_from_name(skill).unwrap();
+    let invariant = skill.invariants().iter().find(|x| x.name() == name);
+    Action::Invariant(invariant.unwrap().id())
+}
+
+/// A handler terminating with the statuses in order, logging its callbacks.
+struct Script {
+    statuses: Vec<Status>,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl SkillHandler for Script {
+    fn on_start(&mut self, skill: &Skill) {
+        self.log
+            .borrow_mut()
+            .push(format!("start {}", skill.name()));
+    }
+
+    fn on_step(&mut self, _skill: &Skill) -> Status {
+        if self.statuses.is_empty() {
+            Status::Running
+        } else {
+            self.statuses.remove(0)
+        }
+    }
+
+    fn on_invariant_failure(&mut self, skill: &Skill, invariant: &Invariant) {
+        self.log
+            .borrow_mut()
+            .push(format!("invariant {}.{}", skill.name(), invariant.name()));
+    }
+
+    fn on_success(&mut self, skill: &Skill, success: &Success) {
+        self.log
+            .borrow_mut()
+            .push(format!("success {}.{}", skill.name(), success.name()));
+    }
+}
+
+#[test]
+fn refusal() {
+    let mut engine = engine();
+    engine.notify("power_off").unwrap();
+    let configuration = engine.configuration().clone();
+    assert_eq!(
+        engine.start("move"),
+        Err(RuntimeError::Disabled {
+            action: "start move".into(),
+            condition: "precondition powered".into(),
+        })
+    );
+    assert_eq!(
+        engine.notify("power_off"),
+        Err(RuntimeError::Disabled {
+            action: "event power_off".into(),
+            condition: "guard".into(),
+        })
+    );
+    assert_eq!(
+        engine.interrupt("move"),
+        Err(RuntimeError::NotRunning("move".into()))
+    );
+    assert_eq!(engine.configuration(), &configuration);
+
+    engine.notify("power_on").unwrap();
+    engine.start("move").unwrap();
+    assert_eq!(
+        engine.start("move"),
+        Err(RuntimeError::AlreadyRunning("move".into()))
+    );
+    assert_eq!(
+        engine.succeed("move", "blocked"),
+        Err(RuntimeError::UnknownMode {
+            skill: "move".into(),
+            mode: "blocked".into(),
+        })
+    );
+    assert!(engine.is_running(skill(&engine, "move")));
+}
+
+#[test]
+fn invariant_cascade() {
+    let mut engine = engine();
+    engine.start("move").unwrap();
+    engine.start("watch").unwrap();
+    let power_off = engine.skillset().get_event_from_name("power_off").unwrap();
+    let power_off = Action::Event(power_off.id());
+    let reports = engine.notify("power_off").unwrap();
+    // The invariant effect of move stops watch in turn
+    assert_eq!(
+        reports,
+        vec![
+            Report::Action(power_off),
+            Report::Action(invariant(&engine, "move", "powered")),
+            Report::Action(invariant(&engine, "watch", "arm_moving")),
+        ]
+    );
+    assert!(!engine.is_running(skill(&engine, "move")));
+    assert!(!engine.is_running(skill(&engine, "watch")));
+    assert_eq!(state(&engine, "arm"), "Folded");
+}
+
+#[test]
+fn postcondition_report() {
+    let mut engine = engine();
+    let id = skill(&engine, "move");
+    let arrived = engine
+        .skillset()
+        .get(id)
+        .unwrap()
+        .get_success_from_name("arrived")
+        .unwrap()
+        .id();
+    engine.start("move").unwrap();
+    let reports = engine.succeed("move", "arrived").unwrap();
+    assert_eq!(
+        reports,
+        vec![
+            Report::Postcondition(Action::Success(arrived)),
+            Report::Action(Action::Success(arrived)),
+        ]
+    );
+    assert!(!engine.is_running(id));
+    assert_eq!(state(&engine, "arm"), "Folded");
+
+    engine.start("move").unwrap();
+    engine.notify("reach").unwrap();
+    let reports = engine.succeed("move", "arrived").unwrap();
+    assert_eq!(reports, vec![Report::Action(Action::Success(arrived))]);
+}
+
+#[test]
+fn step() {
+    let mut engine = engine();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let statuses = vec![Status::Running, Status::Success("unknown".into())];
+    let handler = Script {
+        statuses,
+        log: log.clone(),
+    };
+    engine.set_handler("move", handler).unwrap();
+    let statuses = vec![Status::Running, Status::Success("done".into())];
+    let handler = Script {
+        statuses,
+        log: log.clone(),
+    };
+    engine.set_handler("watch", handler).unwrap();
+    engine.start("move").unwrap();
+    engine.start("watch").unwrap();
+
+    assert!(engine.step().is_empty());
+    // The refused termination of move does not stop polling watch
+    let watch = engine.skillset().get_skill_from_name("watch").unwrap();
+    let done = watch.get_success_from_name("done").unwrap().id();
+    assert_eq!(
+        engine.step(),
+        vec![
+            Err(RuntimeError::UnknownMode {
+                skill: "move".into(),
+                mode: "unknown".into(),
+            }),
+            Ok(Report::Action(Action::Success(done))),
+        ]
+    );
+    assert!(engine.is_running(skill(&engine, "move")));
+    assert!(!engine.is_running(skill(&engine, "watch")));
+    assert_eq!(
+        *log.borrow(),
+        vec!["start move", "start watch", "success watch.done"]
+    );
+}
+
+#[test]
+fn interrupting() {
+    let mut engine = engine();
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let statuses = vec![Status::Running, Status::Interrupted];
+    let handler = Script {
+        statuses,
+        log: log.clone(),
+    };
+    engine.set_handler("grasp", handler).unwrap();
+    let id = skill(&engine, "grasp");
+    engine.start("grasp").unwrap();
+    assert_eq!(
+        engine.interrupted("grasp"),
+        Err(RuntimeError::NotInterrupting("grasp".into()))
+    );
+    // The interrupt is only requested
+    assert_eq!(
+        engine.interrupt("grasp").unwrap(),
+        vec![Report::Interrupting(id)]
+    );
+    assert!(engine.is_running(id));
+    assert!(engine.is_interrupting(id));
+    assert!(engine.step().is_empty());
+    assert_eq!(
+        engine.step(),
+        vec![Ok(Report::Action(Action::Interrupt(id)))]
+    );
+    assert!(!engine.is_running(id));
+    assert!(!engine.is_interrupting(id));
+    // A termination clears the request
+    engine.start("grasp").unwrap();
+    engine.interrupt("grasp").unwrap();
+    engine.succeed("grasp", "held").unwrap();
+    assert!(!engine.is_interrupting(id));
+}
@@ -0,0 +1,135 @@
+use rl_model::doc::*;
+use rl_model::export::*;
+use rl_model::graph::{to_mermaid, to_plantuml};
+use rl_model::load_skillset;
+use rl_model::model::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Exports of the skillsets of examples/tests/verif compared to the golden
+// files tests/golden/<name>.<extension>. Run with UPDATE_GOLDEN=1 to write
+// the golden files from the current exports.
+
+fn skillsets() -> Vec<PathBuf> {
+    let mut v: Vec<PathBuf> = fs::read_dir("examples/tests/verif")
+        .unwrap()
+        .map(|x| x.unwrap().path())
+        .filter(|x| x.extension().is_some_and(|e| e == "rl"))
+        .collect();
+    v.sort();
+    v
+}
+
+fn check(extension: &str, export: fn(&Skillset) -> String) {
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    let mut mismatches = Vec::new();
+    for path in skillsets() {
+        let skillset = match load_skillset(path.to_str().unwrap()) {
+            Ok(skillset) => skillset,
+            Err(e) => panic!("{}: {}", path.display(), e),
+        };
+        let text = export(&skillset);
+        let golden = Path::new("tests/golden")
+            .join(path.file_stem().unwrap())
+            .with_extension(extension);
+        if update {
+            fs::create_dir_all(golden.parent().unwrap()).unwrap();
+            fs::write(&golden, text).unwrap();
+        } else if fs::read_to_string(&golden).ok().as_deref() != Some(text.as_str()) {
+            mismatches.push(golden.display().to_string());
+        }
+    }
+    assert!(mismatches.is_empty(), "differs from {:?}", mismatches);
+}
+
+#[test]
+fn smv() {
+    check("smv", to_smv);
+}
+
+#[test]
+fn smtlib() {
+    check("smt2", |x| to_smtlib(x, 10, None));
+}
+
+#[test]
+fn plantuml() {
+    check("puml", to_plantuml);
+}
+
+#[test]
+fn mermaid() {
+    check("mmd", to_mermaid);
+}
+
+#[test]
+fn fiacre() {
+    check("fcr", to_fiacre);
+}
+
+#[test]
+fn uppaal() {
+    check("xml", to_uppaal);
+}
+
+#[test]
+fn pddl_domain() {
+    check("domain.pddl", to_pddl_domain);
+}
+
+/// The problem of reaching the last state of the first resource.
+fn problem(skillset: &Skillset) -> String {
+    let goal = match skillset.resources().first() {
+        Some(resource) => Expr::ResourceEq(
+            Reference::Resolved(resource.id()),
+            Reference::Resolved(resource.states().last().unwrap().id()),
+        ),
+        None => Expr::True,
+    };
+    let name = format!("{}_problem", skillset.name());
+    to_pddl_problem(skillset, &name, &Valuation::initial(skillset), &goal)
+}
+
+#[test]
+fn pddl_problem() {
+    check("problem.pddl", problem);
+}
+
+#[test]
+fn promela() {
+    check("pml", |x| to_promela(x, None));
+}
+
+#[test]
+fn tla() {
+    check("tla", |x| to_tla(x, None));
+}
+
+#[test]
+fn tla_cfg() {
+    check("cfg", |x| to_tla_cfg(x, false));
+}
+
+#[test]
+fn prism() {
+    check("prism", to_prism);
+}
+
+/// The documentation pages one after the other, each after its file name.
+fn pages(skillset: &Skillset, render: fn(&Page) -> String, extension: &str) -> String {
+    documentation(skillset)
+        .iter()
+        .map(|x| format!("==> {}.{} <==\n{}", x.name(), extension, render(x)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[test]
+fn markdown() {
+    check("md", |x| pages(x, to_markdown, "md"));
+}
+
+#[test]
+fn html() {
+    check("html", |x| pages(x, to_html, "html"));
+}
@@ -0,0 +1,23 @@
+---- MODULE S ----
+
+VARIABLES R
+
+vars == <<R>>
+
+TypeOK ==
+    /\ R \in {"A", "B"}
+
+Init ==
+    /\ R = "A"
+
+\* event E
+event_E ==
+    /\ (R = "A" \/ R = "B")
+    /\ R' = "B"
+
+Next ==
+    \/ event_E
+
+Spec == Init /\ [][Next]_vars
+
+====
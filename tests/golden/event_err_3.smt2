@@ -0,0 +1,48 @@
+; skillset S
+(set-logic ALL)
+; Resources
+(declare-datatypes ((R_t 0)) (((R_A) (R_B))))
+; Actions
+(declare-datatypes ((Action 0)) (((stutter) (event_E))))
+; Initial state
+(define-fun init ((R R_t)) Bool
+	(= R R_A))
+; Transition relation
+(define-fun trans ((R R_t) (action Action) (R.next R_t)) Bool
+	(and
+		(=> (= action stutter) (= R.next R))
+		(=> (= action event_E) (and (= R R_B) (= R.next R_A)))))
+; Unrolling for 10 steps
+(declare-const R.0 R_t)
+(assert (init R.0))
+(declare-const R.1 R_t)
+(declare-const action.0 Action)
+(assert (trans R.0 action.0 R.1))
+(declare-const R.2 R_t)
+(declare-const action.1 Action)
+(assert (trans R.1 action.1 R.2))
+(declare-const R.3 R_t)
+(declare-const action.2 Action)
+(assert (trans R.2 action.2 R.3))
+(declare-const R.4 R_t)
+(declare-const action.3 Action)
+(assert (trans R.3 action.3 R.4))
+(declare-const R.5 R_t)
+(declare-const action.4 Action)
+(assert (trans R.4 action.4 R.5))
+(declare-const R.6 R_t)
+(declare-const action.5 Action)
+(assert (trans R.5 action.5 R.6))
+(declare-const R.7 R_t)
+(declare-const action.6 Action)
+(assert (trans R.6 action.6 R.7))
+(declare-const R.8 R_t)
+(declare-const action.7 Action)
+(assert (trans R.7 action.7 R.8))
+(declare-const R.9 R_t)
+(declare-const action.8 Action)
+(assert (trans R.8 action.8 R.9))
+(declare-const R.10 R_t)
+(declare-const action.9 Action)
+(assert (trans R.9 action.9 R.10))
+(check-sat)
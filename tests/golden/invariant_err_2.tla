@@ -0,0 +1,55 @@
+---- MODULE S ----
+
+VARIABLES R, S_status
+
+vars == <<R, S_status>>
+
+TypeOK ==
+    /\ R \in {"A", "B", "C"}
+    /\ S_status \in {"idle", "running"}
+
+Init ==
+    /\ R = "A"
+    /\ S_status = "idle"
+
+\* start S
+S_start ==
+    /\ S_status = "idle"
+    /\ R = "A"
+    /\ R' = "B"
+    /\ S_status' = "running"
+
+\* invariant S.p1
+S_invariant_p1 ==
+    /\ S_status = "running"
+    /\ ~(R = "B")
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<R>>
+
+\* invariant S.p2
+S_invariant_p2 ==
+    /\ S_status = "running"
+    /\ ~((R # "A" /\ R # "C"))
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<R>>
+
+\* interrupt S
+S_interrupt ==
+    /\ S_status = "running"
+    /\ ((R = "B" /\ (R # "A" /\ R # "C")) /\ TRUE)
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<R>>
+
+Next ==
+    \/ S_start
+    \/ S_invariant_p1
+    \/ S_invariant_p2
+    \/ S_interrupt
+
+Spec == Init /\ [][Next]_vars
+
+S_p1_at_start == [][S_status = "idle" /\ S_status' = "running" => (R = "B")']_vars
+
+S_p2_at_start == [][S_status = "idle" /\ S_status' = "running" => ((R # "A" /\ R # "C"))']_vars
+
+====
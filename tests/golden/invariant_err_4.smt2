@@ -0,0 +1,61 @@
+; skillset S
+(set-logic ALL)
+; Resources
+(declare-datatypes ((R_t 0)) (((R_A) (R_B) (R_C))))
+; Actions
+(declare-datatypes ((Action 0)) (((stutter) (S_start) (S_invariant_p1) (S_interrupt))))
+; Initial state
+(define-fun init ((R R_t) (S_running Bool)) Bool
+	(and (= R R_A) (not S_running)))
+; Transition relation
+(define-fun trans ((R R_t) (S_running Bool) (action Action) (R.next R_t) (S_running.next Bool)) Bool
+	(and
+		(=> (= action stutter) (and (= R.next R) (= S_running.next S_running)))
+		(=> (= action S_start) (and (not S_running) (= R R_A) (= R.next R_B) (= S_running.next true)))
+		(=> (= action S_invariant_p1) (and S_running (not (= R R_A)) (= R.next R) (= S_running.next false)))
+		(=> (= action S_interrupt) (and S_running (and (= R R_A) true) (= R.next R) (= S_running.next false)))))
+; Unrolling for 10 steps
+(declare-const R.0 R_t)
+(declare-const S_running.0 Bool)
+(assert (init R.0 S_running.0))
+(declare-const R.1 R_t)
+(declare-const S_running.1 Bool)
+(declare-const action.0 Action)
+(assert (trans R.0 S_running.0 action.0 R.1 S_running.1))
+(declare-const R.2 R_t)
+(declare-const S_running.2 Bool)
+(declare-const action.1 Action)
+(assert (trans R.1 S_running.1 action.1 R.2 S_running.2))
+(declare-const R.3 R_t)
+(declare-const S_running.3 Bool)
+(declare-const action.2 Action)
+(assert (trans R.2 S_running.2 action.2 R.3 S_running.3))
+(declare-const R.4 R_t)
+(declare-const S_running.4 Bool)
+(declare-const action.3 Action)
+(assert (trans R.3 S_running.3 action.3 R.4 S_running.4))
+(declare-const R.5 R_t)
+(declare-const S_running.5 Bool)
+(declare-const action.4 Action)
+(assert (trans R.4 S_running.4 action.4 R.5 S_running.5))
+(declare-const R.6 R_t)
+(declare-const S_running.6 Bool)
+(declare-const action.5 Action)
+(assert (trans R.5 S_running.5 action.5 R.6 S_running.6))
+(declare-const R.7 R_t)
+(declare-const S_running.7 Bool)
+(declare-const action.6 Action)
+(assert (trans R.6 S_running.6 action.6 R.7 S_running.7))
+(declare-const R.8 R_t)
+(declare-const S_running.8 Bool)
+(declare-const action.7 Action)
+(assert (trans R.7 S_running.7 action.7 R.8 S_running.8))
+(declare-const R.9 R_t)
+(declare-const S_running.9 Bool)
+(declare-const action.8 Action)
+(assert (trans R.8 S_running.8 action.8 R.9 S_running.9))
+(declare-const R.10 R_t)
+(declare-const S_running.10 Bool)
+(declare-const action.9 Action)
+(assert (trans R.9 S_running.9 action.9 R.10 S_running.10))
+(check-sat)
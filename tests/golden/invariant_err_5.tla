@@ -0,0 +1,65 @@
+---- MODULE S ----
+
+VARIABLES R, Q, S_status
+
+vars == <<R, Q, S_status>>
+
+TypeOK ==
+    /\ R \in {"A", "B", "C"}
+    /\ Q \in {"On", "Off"}
+    /\ S_status \in {"idle", "running"}
+
+Init ==
+    /\ R = "A"
+    /\ Q = "Off"
+    /\ S_status = "idle"
+
+\* event on
+event_on ==
+    /\ TRUE
+    /\ Q' = "On"
+    /\ UNCHANGED <<R, S_status>>
+
+\* event off
+event_off ==
+    /\ TRUE
+    /\ Q' = "Off"
+    /\ UNCHANGED <<R, S_status>>
+
+\* start S
+S_start ==
+    /\ S_status = "idle"
+    /\ (R = "A" /\ Q = "Off")
+    /\ R' = "B"
+    /\ S_status' = "running"
+    /\ UNCHANGED <<Q>>
+
+\* invariant S.p1
+S_invariant_p1 ==
+    /\ S_status = "running"
+    /\ ~(Q = "Off")
+    /\ R' = "C"
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<Q>>
+
+\* interrupt S
+S_interrupt ==
+    /\ S_status = "running"
+    /\ (Q = "Off" /\ TRUE)
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<R, Q>>
+
+Next ==
+    \/ event_on
+    \/ event_off
+    \/ S_start
+    \/ S_invariant_p1
+    \/ S_interrupt
+
+Spec == Init /\ [][Next]_vars
+
+S_p1_at_start == [][S_status = "idle" /\ S_status' = "running" => (Q = "Off")']_vars
+
+safe_holds == ~((R = "C" /\ Q = "On"))
+
+====
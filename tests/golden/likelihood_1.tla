@@ -0,0 +1,71 @@
+---- MODULE S ----
+
+VARIABLES R, Q, S_status
+
+vars == <<R, Q, S_status>>
+
+TypeOK ==
+    /\ R \in {"A", "B"}
+    /\ Q \in {"Near", "Far"}
+    /\ S_status \in {"idle", "running"}
+
+Init ==
+    /\ R = "A"
+    /\ Q = "Far"
+    /\ S_status = "idle"
+
+\* event approach
+event_approach ==
+    /\ TRUE
+    /\ Q' = "Near"
+    /\ UNCHANGED <<R, S_status>>
+
+\* event away
+event_away ==
+    /\ TRUE
+    /\ Q' = "Far"
+    /\ UNCHANGED <<R, S_status>>
+
+\* start S
+S_start ==
+    /\ S_status = "idle"
+    /\ R = "A"
+    /\ R' = "B"
+    /\ S_status' = "running"
+    /\ UNCHANGED <<Q>>
+
+\* interrupt S
+S_interrupt ==
+    /\ S_status = "running"
+    /\ TRUE
+    /\ R' = "A"
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<Q>>
+
+\* success S.s
+S_success_s ==
+    /\ S_status = "running"
+    /\ Q = "Near"
+    /\ R' = "A"
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<Q>>
+
+\* failure S.f
+S_failure_f ==
+    /\ S_status = "running"
+    /\ TRUE
+    /\ R' = "A"
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<Q>>
+
+Next ==
+    \/ event_approach
+    \/ event_away
+    \/ S_start
+    \/ S_interrupt
+    \/ S_success_s
+    \/ S_failure_f
+
+Spec == Init /\ [][Next]_vars
+
+====
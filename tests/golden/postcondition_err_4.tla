@@ -0,0 +1,43 @@
+---- MODULE S ----
+
+VARIABLES R, S_status
+
+vars == <<R, S_status>>
+
+TypeOK ==
+    /\ R \in {"A", "B", "C"}
+    /\ S_status \in {"idle", "running"}
+
+Init ==
+    /\ R = "A"
+    /\ S_status = "idle"
+
+\* start S
+S_start ==
+    /\ S_status = "idle"
+    /\ R = "A"
+    /\ R' = "B"
+    /\ S_status' = "running"
+
+\* interrupt S
+S_interrupt ==
+    /\ S_status = "running"
+    /\ TRUE
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<R>>
+
+\* success S.s
+S_success_s ==
+    /\ S_status = "running"
+    /\ R = "B"
+    /\ R' = "C"
+    /\ S_status' = "idle"
+
+Next ==
+    \/ S_start
+    \/ S_interrupt
+    \/ S_success_s
+
+Spec == Init /\ [][Next]_vars
+
+====
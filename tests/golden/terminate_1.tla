@@ -0,0 +1,61 @@
+---- MODULE S ----
+
+VARIABLES R, S_status
+
+vars == <<R, S_status>>
+
+TypeOK ==
+    /\ R \in {"A", "B", "C"}
+    /\ S_status \in {"idle", "running"}
+
+Init ==
+    /\ R = "A"
+    /\ S_status = "idle"
+
+\* start S
+S_start ==
+    /\ S_status = "idle"
+    /\ R = "A"
+    /\ R' = "B"
+    /\ S_status' = "running"
+
+\* invariant S.p1
+S_invariant_p1 ==
+    /\ S_status = "running"
+    /\ ~(R = "B")
+    /\ S_status' = "idle"
+    /\ UNCHANGED <<R>>
+
+\* interrupt S
+S_interrupt ==
+    /\ S_status = "running"
+    /\ (R = "B" /\ TRUE)
+    /\ R' = "C"
+    /\ S_status' = "idle"
+
+\* success S.s
+S_success_s ==
+    /\ S_status = "running"
+    /\ (R = "B" /\ TRUE)
+    /\ R' = "C"
+    /\ S_status' = "idle"
+
+\* failure S.f
+S_failure_f ==
+    /\ S_status = "running"
+    /\ (R = "B" /\ TRUE)
+    /\ R' = "C"
+    /\ S_status' = "idle"
+
+Next ==
+    \/ S_start
+    \/ S_invariant_p1
+    \/ S_interrupt
+    \/ S_success_s
+    \/ S_failure_f
+
+Spec == Init /\ [][Next]_vars
+
+S_p1_at_start == [][S_status = "idle" /\ S_status' = "running" => (R = "B")']_vars
+
+====
@@ -0,0 +1,197 @@
+#![cfg(feature = "async")]
+
+use rl_model::load_skillset;
+use rl_model::model::*;
+use rl_model::runtime::*;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::time::sleep;
+
+// The tests run with a paused clock, that tokio advances when every task is
+// waiting, so that periods are exact.
+
+fn manager() -> Manager {
+    match load_skillset("examples/tests/runtime/robot_1.rl") {
+        Ok(skillset) => Manager::new(skillset),
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn skill(manager: &Manager, name: &str) -> SkillId {
+    manager.skillset().get_skill_from_name(name).unwrap().id()
+}
+
+/// Sets the flag when the execution owning it is dropped.
+struct Guard(Arc<AtomicBool>);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// An execution that never terminates.
+fn pending(dropped: &Arc<AtomicBool>) -> impl Future<Output = Outcome> + Send + 'static {
+    let guard = Guard(dropped.clone());
+    async move {
+        let _guard = guard;
+        std::future::pending().await
+    }
+}
+
+/// The notifications received so far, but the stale data.
+fn received(receiver: &mut broadcast::Receiver<Notification>) -> Vec<Notification> {
+    let mut v = Vec::new();
+    while let Ok(notification) = receiver.try_recv() {
+        if !matches!(notification, Notification::Stale(_)) {
+            v.push(notification);
+        }
+    }
+    v
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancel_on_interrupt() {
+    let manager = manager();
+    let dropped = Arc::new(AtomicBool::new(false));
+    manager.start("move", pending(&dropped)).unwrap();
+    sleep(Duration::from_millis(10)).await;
+    assert!(!dropped.load(Ordering::SeqCst));
+    manager.interrupt("move").unwrap();
+    sleep(Duration::from_millis(10)).await;
+    assert!(dropped.load(Ordering::SeqCst));
+    assert!(!manager.configuration().is_running(skill(&manager, "move")));
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancel_on_invariant() {
+    let manager = manager();
+    let mut receiver = manager.subscribe();
+    let dropped = Arc::new(AtomicBool::new(false));
+    manager.start("move", pending(&dropped)).unwrap();
+    manager.notify("power_off").unwrap();
+    sleep(Duration::from_millis(10)).await;
+    assert!(dropped.load(Ordering::SeqCst));
+    let skillset = manager.skillset();
+    let id = skill(&manager, "move");
+    let powered = skillset.get(id).unwrap().invariants()[0].id();
+    let power_off = skillset.get_event_from_name("power_off").unwrap().id();
+    assert_eq!(
+        received(&mut receiver),
+        vec![
+            Notification::Report(Report::Action(Action::Start(id))),
+            Notification::Report(Report::Action(Action::Event(power_off))),
+            Notification::Report(Report::Action(Action::Invariant(powered))),
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn progress() {
+    let manager = manager();
+    let mut receiver = manager.subscribe();
+    let dropped = Arc::new(AtomicBool::new(false));
+    manager.start("move", pending(&dropped)).unwrap();
+    sleep(Duration::from_millis(350)).await;
+    manager.interrupt("move").unwrap();
+    sleep(Duration::from_millis(350)).await;
+    let id = skill(&manager, "move");
+    let progress: Vec<Notification> = received(&mut receiver)
+        .into_iter()
+        .filter(|x| matches!(x, Notification::Progress(_)))
+        .collect();
+    assert_eq!(progress, vec![Notification::Progress(id); 3]);
+}
+
+#[tokio::test(start_paused = true)]
+async fn watchdog() {
+    let manager = manager();
+    let mut receiver = manager.subscribe();
+    let position = manager
+        .skillset()
+        .get_data_from_name("position")
+        .unwrap()
+        .id();
+    // Reported once while not refreshed
+    sleep(Duration::from_millis(550)).await;
+    let mut stale = Vec::new();
+    while let Ok(notification) = receiver.try_recv() {
+        stale.push(notification);
+    }
+    assert_eq!(stale, vec![Notification::Stale(position)]);
+    // Refreshed within its period
+    for _ in 0..5 {
+        manager.refresh("position").unwrap();
+        sleep(Duration::from_millis(50)).await;
+    }
+    assert!(receiver.try_recv().is_err());
+    sleep(Duration::from_millis(250)).await;
+    assert_eq!(receiver.try_recv(), Ok(Notification::Stale(position)));
+    assert_eq!(
+        manager.refresh("speed"),
+        Err(RuntimeError::UnknownData("speed".into()))
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn refused() {
+    let manager = manager();
+    let mut receiver = manager.subscribe();
+    let execution = async { Outcome::Success("unknown".into()) };
+    manager.start("move", execution).unwrap();
+    sleep(Duration::from_millis(10)).await;
+    let id = skill(&manager, "move");
+    let error = RuntimeError::UnknownMode {
+        skill: "move".into(),
+        mode: "unknown".into(),
+    };
+    assert_eq!(
+        received(&mut receiver),
+        vec![
+            Notification::Report(Report::Action(Action::Start(id))),
+            Notification::Refused(id, error),
+            Notification::Report(Report::Action(Action::Interrupt(id))),
+        ]
+    );
+    assert!(!manager.configuration().is_running(id));
+    // The skill can run a new execution
+    let dropped = Arc::new(AtomicBool::new(false));
+    manager.start("move", pending(&dropped)).unwrap();
+    manager.interrupt("move").unwrap();
+    sleep(Duration::from_millis(10)).await;
+    assert!(dropped.load(Ordering::SeqCst));
+}
+
+#[tokio::test(start_paused = true)]
+async fn interrupting() {
+    let manager = manager();
+    let mut receiver = manager.subscribe();
+    let id = skill(&manager, "grasp");
+    // The execution completes the interrupt once requested
+    let mut requests = manager.subscribe();
+    let execution = async move {
+        while let Ok(notification) = requests.recv().await {
+            if notification == Notification::Report(Report::Interrupting(id)) {
+                break;
+            }
+        }
+        Outcome::Interrupted
+    };
+    manager.start("grasp", execution).unwrap();
+    sleep(Duration::from_millis(10)).await;
+    manager.interrupt("grasp").unwrap();
+    assert!(manager.configuration().is_running(id));
+    sleep(Duration::from_millis(10)).await;
+    assert!(!manager.configuration().is_running(id));
+    assert_eq!(
+        received(&mut receiver),
+        vec![
+            Notification::Report(Report::Action(Action::Start(id))),
+            Notification::Report(Report::Interrupting(id)),
+            Notification::Report(Report::Action(Action::Interrupt(id))),
+        ]
+    );
+}
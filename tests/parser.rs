@@ -0,0 +1,57 @@
+use rl_model::load_skillset;
+use rl_model::model::*;
+
+fn skillset(file: &str) -> Skillset {
+    match load_skillset(file) {
+        Ok(skillset) => skillset,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+#[test]
+fn uav() {
+    let skillset = skillset("examples/uav_skillset.rl");
+    let extern_resources: Vec<&str> = skillset
+        .resources()
+        .iter()
+        .filter(|x| x.attributes().has("extern"))
+        .map(|x| x.name())
+        .collect();
+    assert_eq!(
+        extern_resources,
+        vec!["authority", "home_status", "flight_status", "battery"]
+    );
+    let takeoff = skillset.get_skill_from_name("takeoff").unwrap();
+    assert!(takeoff.interrupt().as_ref().unwrap().interrupting());
+}
+
+#[test]
+fn attributes() {
+    let skillset = skillset("examples/tests/parsing/attribute_1.rl");
+    let resource = &skillset.resources()[0];
+    let transitions = match resource.transitions() {
+        Transitions::List(l) => l,
+        Transitions::All => panic!("transition all"),
+    };
+    assert_eq!(
+        transitions[0].attributes().value("requirement"),
+        Some("REQ-1")
+    );
+    assert!(transitions[1].attributes().all().is_empty());
+    assert_eq!(
+        transitions[0].to_lang(&skillset),
+        "@requirement(\"REQ-1\") s1 -> s2"
+    );
+
+    let skill = skillset.get_skill_from_name("S").unwrap();
+    let interrupt = skill.interrupt().as_ref().unwrap();
+    assert!(interrupt.attributes().has("safe"));
+    let postcondition = &interrupt.postconditions()[0];
+    assert!(postcondition.attributes().has("checked"));
+    assert_eq!(postcondition.to_lang(&skillset), "@checked named: R == s2");
+    assert!(interrupt
+        .to_lang(&skillset)
+        .starts_with("\t\t\tinterrupt @safe {\n"));
+    let success = skill.get_success_from_name("OK").unwrap();
+    assert!(success.postconditions()[0].attributes().all().is_empty());
+}
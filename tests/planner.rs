@@ -0,0 +1,45 @@
+use rl_model::load_skillset;
+use rl_model::model::*;
+use rl_model::parser::parse_expr;
+use rl_model::planner::*;
+
+fn skillset(file: &str) -> Skillset {
+    match load_skillset(&format!("examples/tests/planning/{}", file)) {
+        Ok(skillset) => skillset,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+fn state(skillset: &Skillset, resource: &str, state: &str) -> StateId {
+    let resource = skillset
+        .resources()
+        .iter()
+        .find(|x| x.name() == resource)
+        .unwrap();
+    resource.get_state_from_name(state).unwrap().id()
+}
+
+#[test]
+fn takeoff_outcomes() {
+    let skillset = skillset("takeoff_1.rl");
+    let takeoff = skillset.get_skill_from_name("takeoff").unwrap().id();
+    let initial = Valuation::initial(&skillset);
+    let outcomes: Vec<(bool, StateId)> = outcomes(&skillset, takeoff, &initial)
+        .into_iter()
+        .map(|(outcome, valuation)| (outcome.is_success(), valuation.states()[0]))
+        .collect();
+    let in_air = state(&skillset, "flight_status", "InAir");
+    let on_ground = state(&skillset, "flight_status", "OnGround");
+    assert_eq!(outcomes, vec![(true, in_air), (false, on_ground)]);
+}
+
+#[test]
+fn takeoff_plan() {
+    let skillset = skillset("takeoff_1.rl");
+    let takeoff = skillset.get_skill_from_name("takeoff").unwrap().id();
+    let goal = parse_expr(&skillset, "flight_status == InAir")
+        .ok()
+        .unwrap();
+    let plan = plan(&skillset, &Valuation::initial(&skillset), &goal);
+    assert_eq!(plan, Some(vec![takeoff]));
+}
@@ -0,0 +1,96 @@
+use rl_model::analysis::*;
+use rl_model::load_skillset;
+use rl_model::model::*;
+use rl_model::symbolic::*;
+use std::fs;
+
+// The symbolic engine compared to the explicit exploration on the skillsets
+// of examples/tests/verif and on the UAV example.
+
+fn skillsets() -> Vec<Skillset> {
+    let mut paths: Vec<String> = fs::read_dir("examples/tests/verif")
+        .unwrap()
+        .map(|x| x.unwrap().path().display().to_string())
+        .filter(|x| x.ends_with(".rl"))
+        .collect();
+    paths.sort();
+    paths.push("examples/uav_skillset.rl".into());
+    paths
+        .iter()
+        .map(|path| match load_skillset(path) {
+            Ok(skillset) => skillset,
+            Err(e) => panic!("{}: {}", path, e),
+        })
+        .collect()
+}
+
+#[test]
+fn reachable() {
+    for skillset in skillsets() {
+        let explicit = StateSpace::explore(&skillset);
+        let symbolic = SymbolicSpace::explore(&skillset);
+        assert_eq!(
+            symbolic.count(symbolic.reachable()),
+            explicit.len() as f64,
+            "{}",
+            skillset.name()
+        );
+    }
+    let skillsets = skillsets();
+    let uav = skillsets.iter().find(|x| x.name() == "uav").unwrap();
+    let symbolic = SymbolicSpace::explore(uav);
+    assert_eq!(symbolic.count(symbolic.reachable()), 720.0);
+}
+
+#[test]
+fn deadlocks() {
+    for skillset in skillsets() {
+        let explicit = StateSpace::explore(&skillset);
+        let mut symbolic = SymbolicSpace::explore(&skillset);
+        let deadlocks = symbolic.deadlocks();
+        let expected: Vec<usize> = (0..explicit.len())
+            .filter(|i| explicit.successors(*i).is_empty())
+            .collect();
+        assert_eq!(
+            symbolic.count(deadlocks),
+            expected.len() as f64,
+            "{}",
+            skillset.name()
+        );
+        // the witness leads to a deadlock of the explicit exploration
+        if let Some((configuration, witness)) = symbolic.witness(deadlocks) {
+            let i = explicit.index_of(&configuration).unwrap();
+            assert!(expected.contains(&i), "{}", skillset.name());
+            let mut current = Configuration::initial(&skillset);
+            for action in witness {
+                assert!(current.is_enabled(&skillset, &action));
+                current = current.next(&skillset, &action);
+            }
+            assert_eq!(current, configuration);
+        }
+    }
+}
+
+/// The messages of the findings, but the deadlocks that the symbolic
+/// analysis counts instead of listing them.
+fn messages(diagnostics: Vec<Diagnostic>) -> Vec<String> {
+    let mut v: Vec<String> = diagnostics
+        .iter()
+        .map(|x| x.message().to_string())
+        .filter(|x| !x.starts_with("deadlock"))
+        .collect();
+    v.sort();
+    v
+}
+
+#[test]
+fn analyses() {
+    for skillset in skillsets() {
+        assert_eq!(
+            messages(analyze_symbolic(&skillset)),
+            messages(analyze(&skillset)),
+            "{}",
+            skillset.name()
+        );
+    }
+}